@@ -0,0 +1,1856 @@
+use std::env;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicU32;
+
+#[path = "src"]
+#[allow(dead_code, unused_imports)]
+mod src {
+    pub mod kartoffel_nn;
+    pub mod shape;
+}
+
+use src::kartoffel_nn::format::{self, Conv2dShape, Header, LayerDesc, Pool2dShape, Reader};
+use src::kartoffel_nn::unroll::Term;
+use src::kartoffel_nn::{self as nn, Activation, Fix, Num};
+use src::shape::{
+    self, Encoding, Tile, ACTIONS, CHANNELS, OBSERVATIONS, RADAR_CHANNELS, RADAR_SIZE, SCHEMA, SCHEMA_HASH, TURN_LEFT, TURN_RIGHT,
+};
+
+const DEFAULT_MODEL: &str = "models/kartoffel.knn";
+const SYNTHETIC_OBSERVATIONS: usize = 1000;
+
+/// Layer types `kartoffel_nn` doesn't warn about when none of the models use
+/// them, as told by a `kartoffel_layer` cfg for each one that is used.
+const LAYER_TYPES: &[&str] = &[
+    "Dense",
+    "QDense",
+    "BinaryDense",
+    "Elman",
+    "Conv2d",
+    "MaxPool2d",
+    "Sparse",
+    "LayerNorm",
+    "Residual",
+    "Mixture",
+    "Mirrored",
+];
+
+/// Dense layers with at least this fraction of zero weights get stored as
+/// sparse ones.
+const SPARSITY_THRESHOLD: f64 = 0.5;
+
+#[derive(Clone)]
+enum Weights {
+    Dense {
+        weights: Vec<Vec<Fix>>,
+        biases: Vec<Fix>,
+    },
+    QDense {
+        input_frac: u8,
+        weights: Vec<Vec<i8>>,
+        multipliers: Vec<i32>,
+        shifts: Vec<u8>,
+        biases: Vec<Fix>,
+    },
+    Elman {
+        weights: Vec<Vec<Fix>>,
+        recurrent: Vec<Vec<Fix>>,
+        biases: Vec<Fix>,
+    },
+    Conv2d {
+        shape: Conv2dShape,
+        weights: Vec<Fix>,
+        biases: Vec<Fix>,
+    },
+    MaxPool2d {
+        shape: Pool2dShape,
+    },
+    Sparse {
+        /// `(input, weight)` of each neuron's non-zero weights.
+        rows: Vec<Vec<(u16, Fix)>>,
+        biases: Vec<Fix>,
+    },
+    LayerNorm {
+        epsilon: Fix,
+        gamma: Vec<Fix>,
+        beta: Vec<Fix>,
+    },
+    /// Only there until `fold_batch_norms`, in f64 so that folding doesn't
+    /// round twice.
+    BatchNorm {
+        epsilon: f64,
+        gamma: Vec<f64>,
+        beta: Vec<f64>,
+        mean: Vec<f64>,
+        var: Vec<f64>,
+    },
+    /// Not a layer, see `format::KIND_RESIDUAL`.
+    Residual {
+        layers: usize,
+    },
+    /// Not a layer, see `format::KIND_HEAD`.
+    Head {
+        role: u8,
+    },
+    /// Not a layer, see `format::KIND_EXPERT`.
+    Expert,
+}
+
+/// A layer as the build runs it - a copy of the robot's model made of the
+/// same neuron functions as `kartoffel_nn`'s layer types, but not of those
+/// types themselves, so the build's checks cover this copy rather than them.
+#[derive(Clone)]
+struct ModelLayer {
+    inputs: usize,
+    outputs: usize,
+    activation: u8,
+    activation_param: i32,
+    weights: Weights,
+}
+
+impl ModelLayer {
+    fn kind(&self) -> u8 {
+        match self.weights {
+            Weights::Dense { .. } => format::KIND_DENSE,
+            Weights::QDense { .. } => format::KIND_QDENSE,
+            Weights::Elman { .. } => format::KIND_ELMAN,
+            Weights::Conv2d { .. } => format::KIND_CONV2D,
+            Weights::MaxPool2d { .. } => format::KIND_MAX_POOL2D,
+            Weights::Sparse { .. } => format::KIND_SPARSE,
+            Weights::LayerNorm { .. } => format::KIND_LAYER_NORM,
+            Weights::BatchNorm { .. } => format::KIND_BATCH_NORM,
+            Weights::Residual { .. } => format::KIND_RESIDUAL,
+            Weights::Head { .. } => format::KIND_HEAD,
+            Weights::Expert => format::KIND_EXPERT,
+        }
+    }
+
+    fn type_name(&self) -> String {
+        let activation = activation_type(self.activation, self.activation_param);
+
+        match &self.weights {
+            Weights::Dense { .. } => format!("Dense<{}, {}, {activation}>", self.inputs, self.outputs),
+            Weights::QDense { .. } => format!("QDense<{}, {}, {activation}>", self.inputs, self.outputs),
+            Weights::Elman { .. } => format!("Elman<{}, {}, {activation}>", self.inputs, self.outputs),
+
+            Weights::Conv2d { shape, .. } => format!(
+                "Conv2d<{}, {}, {}, {}, {}, {activation}>",
+                self.inputs, self.outputs, shape.in_channels, shape.out_channels, shape.kernel,
+            ),
+
+            Weights::MaxPool2d { .. } => format!("MaxPool2d<{}, {}>", self.inputs, self.outputs),
+
+            Weights::Sparse { rows, .. } => {
+                let nnz: usize = rows.iter().map(Vec::len).sum();
+                format!("Sparse<{}, {}, {nnz}, {activation}>", self.inputs, self.outputs)
+            }
+
+            Weights::LayerNorm { .. } => format!("LayerNorm<{}, {activation}>", self.outputs),
+            Weights::BatchNorm { .. } => unreachable!("batch norms get folded"),
+            Weights::Residual { .. } | Weights::Head { .. } | Weights::Expert => unreachable!("residual blocks, heads and experts aren't layers"),
+        }
+    }
+
+    /// Type of a `Dense` layer fed with the robot's bit-packed observations.
+    fn binary_type_name(&self) -> String {
+        let activation = activation_type(self.activation, self.activation_param);
+
+        format!("BinaryDense<{}, {}, {}, {activation}>", self.inputs, self.outputs, self.inputs.div_ceil(32))
+    }
+
+    /// Number of inputs taken by the layer's grids, if it's a spatial one;
+    /// the rest gets passed through.
+    fn grid_inputs(&self) -> Option<usize> {
+        match &self.weights {
+            Weights::Conv2d { shape, .. } => Some(shape.inputs()),
+            Weights::MaxPool2d { shape } => Some(shape.inputs()),
+            _ => None,
+        }
+    }
+
+    /// Runs the layer on `T`s, `Fix` giving exactly what the robot computes
+    /// as long as nothing overflows, which saturates and gets counted here
+    /// whatever the `overflow-*` features say; `hidden` is the layer's output
+    /// from the previous step, used by recurrent layers.
+    fn forward<T: Num>(&self, input: &[T], hidden: &mut Vec<T>, overflows: &AtomicU32) -> Vec<T> {
+        let mut output: Vec<_> = self
+            .pre_activations(input, hidden, overflows)
+            .into_iter()
+            .map(|z| activate(self.activation, self.activation_param, T::saturate(z, overflows)))
+            .collect();
+
+        if let Some(grid_inputs) = self.grid_inputs() {
+            output.extend_from_slice(&input[grid_inputs..]);
+        }
+
+        hidden.clone_from(&output);
+        output
+    }
+
+    /// What `forward` computes before narrowing its neurons and activating
+    /// them.
+    fn pre_activations<T: Num>(&self, input: &[T], hidden: &[T], overflows: &AtomicU32) -> Vec<T::Wide> {
+        match &self.weights {
+            Weights::Dense { weights, biases } => weights
+                .iter()
+                .zip(biases)
+                .map(|(w, &b)| nn::neuron(input, &cast(w), T::from_fix(b)))
+                .collect(),
+
+            Weights::QDense { input_frac, weights, multipliers, shifts, biases } => {
+                // int8 arithmetic only exists on `Fix`, so that's what other
+                // `T`s go through
+                let input: Vec<_> = input.iter().map(|&x| nn::quantize(x.to_fix(), *input_frac)).collect();
+
+                (0..self.outputs)
+                    .map(|i| nn::qneuron(&input, &weights[i], multipliers[i], shifts[i], biases[i]))
+                    .map(|z| T::from_fix(Fix::saturate(z, overflows)).widen())
+                    .collect()
+            }
+
+            Weights::Elman { weights, recurrent, biases } => (0..self.outputs)
+                .map(|i| nn::neuron(input, &cast(&weights[i]), T::from_fix(biases[i])) + nn::neuron(hidden, &cast(&recurrent[i]), T::ZERO))
+                .collect(),
+
+            Weights::Conv2d { shape, weights, biases } => {
+                let weights = cast(weights);
+
+                grid(shape.out_channels, shape.out_height(), shape.out_width())
+                    .map(|(c, y, x)| nn::conv2d_neuron(shape, input, &weights, T::from_fix(biases[c]), c, y, x))
+                    .collect()
+            }
+
+            Weights::MaxPool2d { shape } => grid(shape.channels, shape.out_height(), shape.out_width())
+                .map(|(c, y, x)| nn::max_pool2d_neuron(shape, input, c, y, x).widen())
+                .collect(),
+
+            Weights::Sparse { rows, biases } => rows
+                .iter()
+                .zip(biases)
+                .map(|(row, &b)| {
+                    let (columns, values): (Vec<_>, Vec<_>) = row.iter().copied().unzip();
+                    nn::sparse_neuron(input, &columns, &cast(&values), T::from_fix(b))
+                })
+                .collect(),
+
+            Weights::LayerNorm { epsilon, gamma, beta } => {
+                let moments = nn::moments(input, T::from_fix(*epsilon), overflows);
+
+                (0..self.outputs)
+                    .map(|i| nn::layer_norm_neuron(input[i], moments, T::from_fix(gamma[i]), T::from_fix(beta[i])))
+                    .collect()
+            }
+
+            Weights::BatchNorm { .. } => unreachable!("batch norms get folded"),
+            Weights::Residual { .. } | Weights::Head { .. } | Weights::Expert => unreachable!("residual blocks, heads and experts aren't layers"),
+        }
+    }
+}
+
+/// Runs the model's weights without `Fix`'s rounding and overflows, as a
+/// reference for it.
+impl Num for f64 {
+    type Wide = f64;
+
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_fix(x: Fix) -> Self {
+        x.to_num()
+    }
+
+    fn to_fix(self) -> Fix {
+        Fix::saturating_from_num(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn widen(self) -> Self {
+        self
+    }
+
+    fn mul_wide(a: Self, w: Self) -> Self {
+        a * w
+    }
+
+    fn narrow(z: Self, _: &AtomicU32) -> Self {
+        z
+    }
+
+    fn saturate(z: Self, _: &AtomicU32) -> Self {
+        z
+    }
+
+    fn div_wide(z: Self, d: i32) -> Self {
+        z / d as f64
+    }
+
+    fn sqrt_wide(z: Self) -> Self {
+        z.sqrt()
+    }
+
+    fn div_int(self, d: i32) -> Self {
+        self / d as f64
+    }
+
+    fn tanh(self) -> Self {
+        self.tanh()
+    }
+
+    fn softmax(logits: &[Self], probabilities: &mut [Self]) {
+        let max = logits.iter().copied().fold(f64::MIN, f64::max);
+        for (p, &x) in probabilities.iter_mut().zip(logits) {
+            *p = (x - max).exp();
+        }
+
+        let sum: f64 = probabilities.iter().sum();
+        for p in probabilities {
+            *p /= sum;
+        }
+    }
+}
+
+fn cast<T: Num>(xs: &[Fix]) -> Vec<T> {
+    xs.iter().map(|&x| T::from_fix(x)).collect()
+}
+
+/// `(channel, y, x)` of every cell of `channels` grids, in storage order.
+fn grid(channels: usize, height: usize, width: usize) -> impl Iterator<Item = (usize, usize, usize)> {
+    (0..channels).flat_map(move |c| (0..height).flat_map(move |y| (0..width).map(move |x| (c, y, x))))
+}
+
+fn activation_type(activation: u8, param: i32) -> String {
+    match activation {
+        format::ACTIVATION_ID => "Id".into(),
+        format::ACTIVATION_RELU => "Relu".into(),
+        format::ACTIVATION_LEAKY_RELU => format!("LeakyRelu<{param}>"),
+        format::ACTIVATION_HARD_TANH => "HardTanh".into(),
+        format::ACTIVATION_HARD_SIGMOID => "HardSigmoid".into(),
+        format::ACTIVATION_TANH => "Tanh".into(),
+        format::ACTIVATION_SIGMOID => "Sigmoid".into(),
+        _ => unreachable!(),
+    }
+}
+
+fn activate<T: Num>(activation: u8, param: i32, x: T) -> T {
+    // `LeakyRelu`'s slope is a const generic, so it can't come from `param`
+    fn leaky_relu<T: Num>(x: T, slope: i32) -> T {
+        if x >= T::ZERO { x } else { x * T::from_fix(Fix::from_bits(slope)) }
+    }
+
+    match activation {
+        format::ACTIVATION_ID => nn::Id.apply(x),
+        format::ACTIVATION_RELU => nn::Relu.apply(x),
+        format::ACTIVATION_LEAKY_RELU => leaky_relu(x, param),
+        format::ACTIVATION_HARD_TANH => nn::HardTanh.apply(x),
+        format::ACTIVATION_HARD_SIGMOID => nn::HardSigmoid.apply(x),
+        format::ACTIVATION_TANH => nn::Tanh.apply(x),
+        format::ACTIVATION_SIGMOID => nn::Sigmoid.apply(x),
+        _ => unreachable!(),
+    }
+}
+
+/// Names and paths of the models to embed - either `KARTOFFEL_MODELS` (e.g.
+/// `exploration=models/exploration.knn,combat=models/combat.knn`) or a single
+/// `kartoffel` one at `KARTOFFEL_MODEL`.
+fn models() -> Vec<(String, PathBuf)> {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_MODEL");
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_MODELS");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    let models: Vec<(String, PathBuf)> = match env::var("KARTOFFEL_MODELS") {
+        Ok(models) => {
+            if env::var_os("KARTOFFEL_MODEL").is_some() {
+                panic!("KARTOFFEL_MODEL and KARTOFFEL_MODELS can't be used together");
+            }
+
+            models
+                .split(',')
+                .map(|model| {
+                    let (name, path) = model
+                        .split_once('=')
+                        .unwrap_or_else(|| panic!("KARTOFFEL_MODELS: expected `name=path`, got `{model}`"));
+
+                    (name.trim().to_string(), manifest_dir.join(path.trim()))
+                })
+                .collect()
+        }
+
+        Err(_) => {
+            let path = env::var("KARTOFFEL_MODEL").unwrap_or_else(|_| DEFAULT_MODEL.into());
+            vec![("kartoffel".to_string(), manifest_dir.join(path))]
+        }
+    };
+
+    for (i, (name, path)) in models.iter().enumerate() {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            panic!("KARTOFFEL_MODELS: model name `{name}` isn't a lowercase identifier");
+        }
+        if models[..i].iter().any(|(other, _)| other == name) {
+            panic!("KARTOFFEL_MODELS: there's more than one model named `{name}`");
+        }
+    }
+
+    models
+}
+
+/// Brings a version 3 blob up to version 4, see `format::V3_SCHEMA_HASH`;
+/// leaves other blobs be.
+fn upgrade(mut blob: Vec<u8>) -> Vec<u8> {
+    if let Some(header) = format::upgrade_v3(&blob) {
+        blob.splice(..format::HEADER_LEN - 4, header);
+    }
+    blob
+}
+
+fn read_model(path: &Path, blob: &[u8]) -> Vec<ModelLayer> {
+    let header = Header::read(blob);
+    if header.int_bits as u32 + header.frac_bits as u32 != 32 {
+        panic!("{}: model uses I{}F{}, which isn't 32 bits wide", path.display(), header.int_bits, header.frac_bits);
+    }
+    if header.layer_count == 0 {
+        panic!("{}: model has no layers", path.display());
+    }
+    if header.schema != SCHEMA_HASH {
+        panic!(
+            "{}: model was trained on observations of schema {:#010x}, but the robot encodes them as {SCHEMA_HASH:#010x} - see `SCHEMA` in src/shape.rs",
+            path.display(),
+            header.schema,
+        );
+    }
+
+    let descs: Vec<_> = (0..header.layer_count).map(|i| LayerDesc::read(blob, i)).collect();
+
+    let mut width = OBSERVATIONS;
+    let mut trunk_width = None;
+    for (i, desc) in descs.iter().enumerate() {
+        if desc.kind > format::KIND_BATCH_NORM {
+            panic!(
+                "{}: layer {i} is of kind {}, which the robot doesn't support - see the kinds in src/kartoffel_nn/format.rs",
+                path.display(),
+                desc.kind,
+            );
+        }
+        if desc.activation > format::ACTIVATION_SIGMOID {
+            panic!(
+                "{}: layer {i} uses activation {}, which the robot doesn't support - see the activations in src/kartoffel_nn/format.rs",
+                path.display(),
+                desc.activation,
+            );
+        }
+        if matches!(desc.kind, format::KIND_MAX_POOL2D | format::KIND_RESIDUAL | format::KIND_HEAD | format::KIND_EXPERT)
+            && desc.activation != format::ACTIVATION_ID
+        {
+            panic!("{}: layer {i} is a pooling layer, a residual block, a head or an expert, so it can't have an activation", path.display());
+        }
+        if matches!(desc.kind, format::KIND_RESIDUAL | format::KIND_LAYER_NORM | format::KIND_BATCH_NORM) && desc.outputs != desc.inputs {
+            panic!("{}: layer {i} produces {} outputs, but a residual block or a norm has to keep its {} inputs", path.display(), desc.outputs, desc.inputs);
+        }
+        if desc.kind == format::KIND_HEAD {
+            width = *trunk_width.get_or_insert(width);
+            if desc.outputs != width {
+                panic!("{}: head {i} produces {} outputs, but the trunk {width}", path.display(), desc.outputs);
+            }
+        }
+        if desc.kind == format::KIND_EXPERT {
+            width = OBSERVATIONS;
+            if desc.outputs != width {
+                panic!("{}: expert {i} produces {} outputs, but the model takes {width}", path.display(), desc.outputs);
+            }
+        }
+        if desc.inputs != width {
+            panic!("{}: layer {i} takes {} inputs, but receives {width}", path.display(), desc.inputs);
+        }
+        width = desc.outputs;
+    }
+
+    let mut len = header.data_offset();
+    for desc in &descs {
+        len += desc.data_len(blob, len);
+    }
+    if blob.len() != len {
+        panic!("{}: model should be {len} bytes long, but is {}", path.display(), blob.len());
+    }
+
+    let mut r = Reader::new(blob, header.data_offset());
+    let mut fixes = Converter { frac_bits: header.frac_bits as u32, saturated: 0 };
+
+    let mut layers: Vec<_> = descs
+        .into_iter()
+        .enumerate()
+        .map(|(i, desc)| {
+            let weights = match desc.kind {
+                format::KIND_DENSE => Weights::Dense {
+                    weights: (0..desc.outputs).map(|_| read_fixes(&mut r, &mut fixes, desc.inputs)).collect(),
+                    biases: read_fixes(&mut r, &mut fixes, desc.outputs),
+                },
+
+                format::KIND_QDENSE => {
+                    let input_frac = r.u8();
+                    if input_frac as u32 > Fix::FRAC_NBITS {
+                        panic!("{}: layer {i} quantizes inputs with more fractional bits than Fix has", path.display());
+                    }
+
+                    let weights = (0..desc.outputs).map(|_| (0..desc.inputs).map(|_| r.i8()).collect()).collect();
+                    let multipliers = (0..desc.outputs).map(|_| r.i32()).collect();
+
+                    // the shifts produce bits of the model's format, so they
+                    // need to make up for the difference
+                    let shifts = (0..desc.outputs)
+                        .map(|_| {
+                            let shift = r.u8() as i32 + header.frac_bits as i32 - Fix::FRAC_NBITS as i32;
+                            u8::try_from(shift).unwrap_or_else(|_| panic!("{}: layer {i}'s shifts don't fit Fix", path.display()))
+                        })
+                        .collect();
+
+                    Weights::QDense {
+                        input_frac,
+                        weights,
+                        multipliers,
+                        shifts,
+                        biases: read_fixes(&mut r, &mut fixes, desc.outputs),
+                    }
+                }
+
+                format::KIND_ELMAN => Weights::Elman {
+                    weights: (0..desc.outputs).map(|_| read_fixes(&mut r, &mut fixes, desc.inputs)).collect(),
+                    recurrent: (0..desc.outputs).map(|_| read_fixes(&mut r, &mut fixes, desc.outputs)).collect(),
+                    biases: read_fixes(&mut r, &mut fixes, desc.outputs),
+                },
+
+                format::KIND_CONV2D => {
+                    let shape = Conv2dShape::read(&mut r);
+                    check_grid(path, i, &desc, shape.inputs(), shape.outputs());
+
+                    Weights::Conv2d {
+                        shape,
+                        weights: read_fixes(&mut r, &mut fixes, shape.weights()),
+                        biases: read_fixes(&mut r, &mut fixes, shape.out_channels),
+                    }
+                }
+
+                format::KIND_MAX_POOL2D => {
+                    let shape = Pool2dShape::read(&mut r);
+                    check_grid(path, i, &desc, shape.inputs(), shape.outputs());
+
+                    Weights::MaxPool2d { shape }
+                }
+
+                format::KIND_SPARSE => {
+                    let nnz = r.u16() as usize;
+                    let row_ends: Vec<_> = (0..desc.outputs).map(|_| r.u16() as usize).collect();
+                    let columns: Vec<_> = (0..nnz).map(|_| r.u16()).collect();
+                    let values = read_fixes(&mut r, &mut fixes, nnz);
+
+                    if row_ends.windows(2).any(|w| w[0] > w[1]) || row_ends.last().is_some_and(|&end| end != nnz) {
+                        panic!("{}: layer {i}'s rows don't add up to its {nnz} weights", path.display());
+                    }
+                    if let Some(c) = columns.iter().find(|&&c| c as usize >= desc.inputs) {
+                        panic!("{}: layer {i} has a weight for input {c}, but only {} inputs", path.display(), desc.inputs);
+                    }
+
+                    let rows = row_ends
+                        .iter()
+                        .scan(0, |start, &end| Some((std::mem::replace(start, end), end)))
+                        .map(|(start, end)| columns[start..end].iter().copied().zip(values[start..end].iter().copied()).collect())
+                        .collect();
+
+                    Weights::Sparse { rows, biases: read_fixes(&mut r, &mut fixes, desc.outputs) }
+                }
+
+                format::KIND_LAYER_NORM => {
+                    let epsilon = fixes.value(r.i32());
+                    if epsilon < 0.0 {
+                        panic!("{}: layer {i}'s epsilon is negative", path.display());
+                    }
+
+                    Weights::LayerNorm {
+                        // an epsilon too small for Fix would be rounded to
+                        // zero, and inputs that are all the same divided by it
+                        epsilon: fixes.convert(epsilon).max(Fix::DELTA),
+                        gamma: read_fixes(&mut r, &mut fixes, desc.outputs),
+                        beta: read_fixes(&mut r, &mut fixes, desc.outputs),
+                    }
+                }
+
+                format::KIND_BATCH_NORM => {
+                    let channels = r.u16() as usize;
+                    let epsilon = fixes.value(r.i32());
+                    if epsilon < 0.0 {
+                        panic!("{}: layer {i}'s epsilon is negative", path.display());
+                    }
+
+                    let mut values = || (0..channels).map(|_| fixes.value(r.i32())).collect();
+                    Weights::BatchNorm { epsilon, gamma: values(), beta: values(), mean: values(), var: values() }
+                }
+
+                format::KIND_RESIDUAL => Weights::Residual { layers: r.u8() as usize },
+                format::KIND_HEAD => Weights::Head { role: r.u8() },
+                format::KIND_EXPERT => Weights::Expert,
+
+                _ => unreachable!(),
+            };
+
+            let activation_param = if desc.activation == format::ACTIVATION_LEAKY_RELU {
+                fixes.fix(desc.activation_param).to_bits()
+            } else {
+                desc.activation_param
+            };
+
+            ModelLayer {
+                inputs: desc.inputs,
+                outputs: desc.outputs,
+                activation: desc.activation,
+                activation_param,
+                weights,
+            }
+        })
+        .collect();
+
+    fold_batch_norms(path, &mut layers, &mut fixes);
+
+    if header.int_bits as u32 != Fix::INT_NBITS {
+        println!(
+            "cargo:warning={}: converting model from I{}F{} into I{}F{}",
+            path.display(),
+            header.int_bits,
+            header.frac_bits,
+            Fix::INT_NBITS,
+            Fix::FRAC_NBITS,
+        );
+    }
+    if fixes.saturated > 0 {
+        println!("cargo:warning={}: {} of the model's values don't fit Fix and got saturated", path.display(), fixes.saturated);
+    }
+
+    check_residuals(path, &layers);
+
+    let experts = experts(&layers);
+    if let Some(first) = experts.first() {
+        if layers.iter().any(|layer| matches!(layer.weights, Weights::Head { .. })) {
+            panic!("{}: model has both heads and experts", path.display());
+        }
+        if first.start == 1 {
+            panic!("{}: model's gate has no layers", path.display());
+        }
+        if layers[first.start - 2].outputs != experts.len() {
+            panic!("{}: model's gate produces {} outputs, but there are {} experts", path.display(), layers[first.start - 2].outputs, experts.len());
+        }
+
+        for (i, expert) in experts.iter().enumerate() {
+            let Some(last) = expert.clone().last() else {
+                panic!("{}: model's expert {i} has no layers", path.display());
+            };
+            if layers[last].outputs != ACTIONS {
+                panic!("{}: model's expert {i} produces {} outputs, but the robot has {ACTIONS} actions", path.display(), layers[last].outputs);
+            }
+        }
+    }
+
+    let mut roles = Vec::new();
+    for (role, head) in heads(&layers) {
+        let name = match role {
+            format::HEAD_POLICY | format::HEAD_VALUE | format::HEAD_ENEMY_NEARBY => head_name(role),
+            _ => panic!("{}: model has a head of unknown role {role}", path.display()),
+        };
+        if roles.contains(&role) {
+            panic!("{}: model has more than one {name} head", path.display());
+        }
+        roles.push(role);
+
+        let Some(last) = head.clone().last() else {
+            panic!("{}: model's {name} head has no layers", path.display());
+        };
+        if layers[last].outputs != head_width(role) {
+            panic!("{}: model's {name} head produces {} outputs, but the robot expects {}", path.display(), layers[last].outputs, head_width(role));
+        }
+    }
+    if !roles.contains(&format::HEAD_POLICY) {
+        panic!("{}: model has no policy head", path.display());
+    }
+
+    layers
+}
+
+/// Role and layers of each of the model's heads - for models without
+/// `Weights::Head`s, that's just a policy head made of all the layers.
+fn heads(layers: &[ModelLayer]) -> Vec<(u8, Range<usize>)> {
+    let markers: Vec<_> = (0..layers.len()).filter(|&i| matches!(layers[i].weights, Weights::Head { .. })).collect();
+    if markers.is_empty() {
+        return vec![(format::HEAD_POLICY, 0..layers.len())];
+    }
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(j, &i)| {
+            let Weights::Head { role } = layers[i].weights else { unreachable!() };
+            (role, i + 1..markers.get(j + 1).copied().unwrap_or(layers.len()))
+        })
+        .collect()
+}
+
+/// Layers of each of the model's experts, which come after the gate - none
+/// for models without `Weights::Expert`s.
+fn experts(layers: &[ModelLayer]) -> Vec<Range<usize>> {
+    let markers: Vec<_> = (0..layers.len()).filter(|&i| matches!(layers[i].weights, Weights::Expert)).collect();
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(j, &i)| i + 1..markers.get(j + 1).copied().unwrap_or(layers.len()))
+        .collect()
+}
+
+/// Number of layers before the first head, shared by all of them - none for
+/// models without `Weights::Head`s, which are a policy head on their own.
+fn trunk_len(layers: &[ModelLayer]) -> usize {
+    layers.iter().position(|layer| matches!(layer.weights, Weights::Head { .. })).unwrap_or(0)
+}
+
+fn head_name(role: u8) -> &'static str {
+    match role {
+        format::HEAD_POLICY => "policy",
+        format::HEAD_VALUE => "value",
+        format::HEAD_ENEMY_NEARBY => "enemy_nearby",
+        _ => unreachable!(),
+    }
+}
+
+fn head_width(role: u8) -> usize {
+    match role {
+        format::HEAD_POLICY => ACTIONS,
+        _ => 1,
+    }
+}
+
+/// Folds each `Weights::BatchNorm` into the dense or convolutional layer
+/// before it, scaling its weights and shifting its biases channel by channel,
+/// and removes it.
+fn fold_batch_norms(path: &Path, layers: &mut Vec<ModelLayer>, fixes: &mut Converter) {
+    // Back to front, so that the layers before keep their indices
+    for i in (0..layers.len()).rev() {
+        let Weights::BatchNorm { epsilon, gamma, beta, mean, var } = layers[i].weights.clone() else {
+            continue;
+        };
+
+        let Some(previous) = i.checked_sub(1) else {
+            panic!("{}: batch norm {i} has no layer before it to fold into", path.display());
+        };
+        if layers[..previous].iter().enumerate().any(|(j, layer)| matches!(layer.weights, Weights::Residual { layers } if j + layers == previous)) {
+            panic!("{}: batch norm {i} comes right after a residual block, which it can't be folded into", path.display());
+        }
+        if layers[previous].activation != format::ACTIVATION_ID {
+            panic!("{}: batch norm {i} comes after an activation, which it can't be folded into", path.display());
+        }
+
+        if let Some(c) = (0..var.len()).find(|&c| var[c] + epsilon <= 0.0) {
+            panic!("{}: batch norm {i}'s variance plus epsilon isn't positive for channel {c}, so it can't be folded", path.display());
+        }
+
+        let scales: Vec<_> = (0..gamma.len()).map(|c| gamma[c] / (var[c] + epsilon).sqrt()).collect();
+
+        // a chunk of weights per channel, either way
+        let (weights, biases): (Vec<&mut Fix>, _) = match &mut layers[previous].weights {
+            Weights::Dense { weights, biases } if biases.len() == scales.len() => (weights.iter_mut().flatten().collect(), biases),
+            Weights::Conv2d { shape, weights, biases } if shape.out_channels == scales.len() => (weights.iter_mut().collect(), biases),
+
+            Weights::Dense { .. } | Weights::Conv2d { .. } => {
+                panic!("{}: batch norm {i} normalizes {} channels, but the layer before it has a different number", path.display(), scales.len());
+            }
+
+            _ => panic!(
+                "{}: batch norm {i} can only be folded into a dense or a convolutional layer before it - the robot can't run it on its own",
+                path.display(),
+            ),
+        };
+
+        let mut folded_weights: Vec<f64> = weights.iter().map(|w| w.to_num()).collect();
+        let mut folded_biases: Vec<f64> = biases.iter().map(|b| b.to_num()).collect();
+        nn::fold_batch_norm(&mut folded_weights, &mut folded_biases, &scales, &mean, &beta);
+        for (w, folded) in weights.into_iter().zip(folded_weights) {
+            *w = fixes.convert(folded);
+        }
+        for (b, folded) in biases.iter_mut().zip(folded_biases) {
+            *b = fixes.convert(folded);
+        }
+
+        layers[previous].activation = layers[i].activation;
+        layers[previous].activation_param = layers[i].activation_param;
+        layers.remove(i);
+
+        for (j, layer) in layers[..i].iter_mut().enumerate() {
+            if let Weights::Residual { layers } = &mut layer.weights {
+                if j + *layers >= i {
+                    *layers -= 1;
+                }
+            }
+        }
+    }
+}
+
+/// Checks that each residual block has layers, ends on the width it starts
+/// with and sits within a single head or expert, and any nested blocks in it.
+fn check_residuals(path: &Path, layers: &[ModelLayer]) {
+    for (i, layer) in layers.iter().enumerate() {
+        let Weights::Residual { layers: len } = layer.weights else {
+            continue;
+        };
+
+        if len == 0 {
+            panic!("{}: residual block {i} has no layers", path.display());
+        }
+        let Some(block) = layers.get(i + 1..i + 1 + len) else {
+            panic!("{}: residual block {i} has {len} layers, but the model ends before that", path.display());
+        };
+        if block[len - 1].outputs != layer.outputs {
+            panic!("{}: residual block {i} produces {} outputs, but takes {}", path.display(), block[len - 1].outputs, layer.outputs);
+        }
+
+        for (j, inner) in block.iter().enumerate() {
+            match inner.weights {
+                Weights::Head { .. } | Weights::Expert => {
+                    panic!("{}: residual block {i} spans more than one head or expert", path.display());
+                }
+
+                Weights::Residual { layers: inner_len } if j + 1 + inner_len > len => {
+                    panic!("{}: residual block {} ends after the residual block {i} it's in", path.display(), i + 1 + j);
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Converts bit patterns of the model's fixed-point format into `Fix`.
+struct Converter {
+    frac_bits: u32,
+    saturated: usize,
+}
+
+impl Converter {
+    fn fix(&mut self, bits: i32) -> Fix {
+        self.convert(self.value(bits))
+    }
+
+    fn value(&self, bits: i32) -> f64 {
+        bits as f64 / 2f64.powi(self.frac_bits as i32)
+    }
+
+    fn convert(&mut self, x: f64) -> Fix {
+        Fix::checked_from_num(x).unwrap_or_else(|| {
+            self.saturated += 1;
+            Fix::saturating_from_num(x)
+        })
+    }
+}
+
+/// Checks that a spatial layer's grids fit its inputs and that the grids it
+/// produces, followed by the passed-through extra features, fill its outputs.
+fn check_grid(path: &Path, i: usize, desc: &LayerDesc, grid_inputs: usize, grid_outputs: usize) {
+    if grid_inputs > desc.inputs {
+        panic!("{}: layer {i}'s grids take {grid_inputs} inputs, but it only has {}", path.display(), desc.inputs);
+    }
+
+    let outputs = grid_outputs + desc.inputs - grid_inputs;
+    if outputs != desc.outputs {
+        panic!("{}: layer {i}'s grids produce {outputs} outputs, but it declares {}", path.display(), desc.outputs);
+    }
+}
+
+fn read_fixes(r: &mut Reader, fixes: &mut Converter, n: usize) -> Vec<Fix> {
+    (0..n).map(|_| fixes.fix(r.i32())).collect()
+}
+
+fn write_model(layers: &[ModelLayer]) -> Vec<u8> {
+    let mut blob = format::MAGIC.to_vec();
+    blob.extend(format::VERSION.to_le_bytes());
+    blob.push(Fix::INT_NBITS as u8);
+    blob.push(Fix::FRAC_NBITS as u8);
+    blob.extend((layers.len() as u16).to_le_bytes());
+    blob.extend(SCHEMA_HASH.to_le_bytes());
+
+    for layer in layers {
+        blob.push(layer.kind());
+        blob.extend((layer.inputs as u16).to_le_bytes());
+        blob.extend((layer.outputs as u16).to_le_bytes());
+        blob.push(layer.activation);
+        blob.extend(layer.activation_param.to_le_bytes());
+    }
+
+    for layer in layers {
+        write_layer_data(layer, &mut blob);
+    }
+
+    blob
+}
+
+/// Appends the layer's data, i.e. everything but its descriptor, to `blob`.
+fn write_layer_data(layer: &ModelLayer, blob: &mut Vec<u8>) {
+    match &layer.weights {
+        Weights::Dense { weights, biases } => {
+            blob.extend(weights.iter().flatten().chain(biases).flat_map(|w| w.to_bits().to_le_bytes()));
+        }
+
+        Weights::QDense { input_frac, weights, multipliers, shifts, biases } => {
+            blob.push(*input_frac);
+            blob.extend(weights.iter().flatten().map(|&w| w as u8));
+            blob.extend(multipliers.iter().flat_map(|m| m.to_le_bytes()));
+            blob.extend(shifts);
+            blob.extend(biases.iter().flat_map(|b| b.to_bits().to_le_bytes()));
+        }
+
+        Weights::Elman { weights, recurrent, biases } => {
+            blob.extend(weights.iter().chain(recurrent).flatten().chain(biases).flat_map(|w| w.to_bits().to_le_bytes()));
+        }
+
+        Weights::Conv2d { shape, weights, biases } => {
+            let dims = [shape.height, shape.width, shape.in_channels, shape.out_channels, shape.kernel, shape.stride, shape.padding];
+            blob.extend(dims.map(|dim| dim as u8));
+            blob.extend(weights.iter().chain(biases).flat_map(|w| w.to_bits().to_le_bytes()));
+        }
+
+        Weights::MaxPool2d { shape } => {
+            blob.extend([shape.height, shape.width, shape.channels, shape.size].map(|dim| dim as u8));
+        }
+
+        Weights::Sparse { rows, biases } => {
+            let row_ends = rows.iter().scan(0, |end, row| {
+                *end += row.len();
+                Some(*end as u16)
+            });
+
+            blob.extend((rows.iter().map(Vec::len).sum::<usize>() as u16).to_le_bytes());
+            blob.extend(row_ends.flat_map(u16::to_le_bytes));
+            blob.extend(rows.iter().flatten().flat_map(|(c, _)| c.to_le_bytes()));
+            blob.extend(rows.iter().flatten().flat_map(|(_, w)| w.to_bits().to_le_bytes()));
+            blob.extend(biases.iter().flat_map(|b| b.to_bits().to_le_bytes()));
+        }
+
+        Weights::LayerNorm { epsilon, gamma, beta } => {
+            blob.extend([*epsilon].iter().chain(gamma).chain(beta).flat_map(|w| w.to_bits().to_le_bytes()));
+        }
+
+        Weights::BatchNorm { .. } => unreachable!("batch norms get folded"),
+
+        Weights::Residual { layers } => {
+            blob.push(*layers as u8);
+        }
+
+        Weights::Head { role } => {
+            blob.push(*role);
+        }
+
+        Weights::Expert => {}
+    }
+}
+
+fn data_len(layer: &ModelLayer) -> usize {
+    let mut blob = Vec::new();
+    write_layer_data(layer, &mut blob);
+    blob.len()
+}
+
+fn initial_state<T: Num>(layers: &[ModelLayer]) -> Vec<Vec<T>> {
+    layers.iter().map(|layer| vec![T::ZERO; layer.outputs]).collect()
+}
+
+/// Outputs of every layer; `Weights::Head`s output the trunk's output and
+/// `Weights::Expert`s the observation, which is what the layer after them
+/// takes. `Weights::Residual`s output their input, which the last layer of
+/// their block adds to its output.
+fn forward<T: Num>(layers: &[ModelLayer], observation: &[T], state: &mut [Vec<T>], overflows: &AtomicU32) -> Vec<Vec<T>> {
+    let mut outputs: Vec<Vec<T>> = Vec::new();
+    let mut trunk = None;
+    // last layer and input of the residual blocks the layer is in
+    let mut blocks: Vec<(usize, Vec<T>)> = Vec::new();
+    for (i, (layer, hidden)) in layers.iter().zip(state).enumerate() {
+        let input = outputs.last().map_or(observation, Vec::as_slice);
+        let mut output = match layer.weights {
+            Weights::Residual { layers } => {
+                blocks.push((i + layers, input.to_vec()));
+                input.to_vec()
+            }
+
+            Weights::Head { .. } => trunk.get_or_insert_with(|| input.to_vec()).clone(),
+            Weights::Expert => observation.to_vec(),
+            _ => layer.forward(input, hidden, overflows),
+        };
+
+        while blocks.last().is_some_and(|(last, _)| *last == i) {
+            let (_, block_input) = blocks.pop().unwrap();
+            for (y, x) in output.iter_mut().zip(block_input) {
+                *y = T::saturate(x.widen() + y.widen(), overflows);
+            }
+        }
+
+        outputs.push(output);
+    }
+    outputs
+}
+
+/// Action logits, out of `forward`'s outputs - the policy head's or, for
+/// models with experts, theirs blended the way `nn::Mixture` does.
+fn policy<T: Num>(layers: &[ModelLayer], outputs: &[Vec<T>], overflows: &AtomicU32) -> Vec<T> {
+    let experts = experts(layers);
+    let Some(first) = experts.first() else {
+        let (_, head) = heads(layers).into_iter().find(|(role, _)| *role == format::HEAD_POLICY).unwrap();
+        return outputs[head.end - 1].clone();
+    };
+
+    let gate = &outputs[first.start - 2];
+    let mut weights = vec![T::ZERO; gate.len()];
+    T::softmax(gate, &mut weights);
+
+    let mut z = vec![T::ZERO.widen(); ACTIONS];
+    for (expert, weight) in experts.iter().zip(weights) {
+        nn::mix(&mut z, weight, &outputs[expert.end - 1]);
+    }
+    z.into_iter().map(|z| T::saturate(z, overflows)).collect()
+}
+
+/// Mirror image of `observation`, its radar grids' left and right swapped.
+fn mirror(observation: &[Fix]) -> Vec<Fix> {
+    let mut mirrored = observation.to_vec();
+    for (y, x) in (0..RADAR_SIZE * RADAR_CHANNELS).flat_map(|y| (0..RADAR_SIZE).map(move |x| (y, x))) {
+        mirrored[y * RADAR_SIZE + RADAR_SIZE - 1 - x] = observation[y * RADAR_SIZE + x];
+    }
+    mirrored
+}
+
+/// Checks that a model trained with tied weights picks the mirror image of
+/// its action for the mirror image of each of `observations` - exactly, as
+/// every product gets rounded the same way in both and the sums are of
+/// integers.
+fn check_tied(layers: &[ModelLayer], observations: &[Vec<Fix>]) {
+    let overflows = AtomicU32::new(0);
+    let mut state = initial_state(layers);
+    let mut mirrored_state = initial_state(layers);
+
+    for observation in observations {
+        let logits = policy(layers, &forward(layers, observation, &mut state, &overflows), &overflows);
+        let mut mirrored = policy(layers, &forward(layers, &mirror(observation), &mut mirrored_state, &overflows), &overflows);
+        mirrored.swap(TURN_LEFT, TURN_RIGHT);
+
+        if logits != mirrored {
+            panic!(
+                "KARTOFFEL_MIRROR=tied, but the model isn't mirror-symmetric - it gives {logits:?} for {observation:?}, \
+                 but {mirrored:?} (turns swapped back) for its mirror image",
+            );
+        }
+    }
+}
+
+fn argmax(xs: &[Fix]) -> usize {
+    xs.iter().enumerate().max_by_key(|(_, x)| **x).map(|(i, _)| i).unwrap()
+}
+
+/// Reads the observations the build runs the models on (to calibrate the
+/// quantized ones and check them and the tied ones) from
+/// `KARTOFFEL_OBSERVATIONS` (one observation per line, values separated by
+/// whitespace, consecutive lines being consecutive steps), falling back to
+/// random radar scans with a few void tiles and bots, encoded the way the
+/// robot does.
+fn observations() -> Vec<Vec<Fix>> {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_OBSERVATIONS");
+
+    let Ok(path) = env::var("KARTOFFEL_OBSERVATIONS") else {
+        let mut seed = 0x2545f491u32;
+        let mut random = move || nn::xorshift(&mut seed) % 16;
+
+        let mut observation = move || {
+            let tiles: Vec<_> = (0..RADAR_SIZE * RADAR_SIZE)
+                .map(|_| match random() {
+                    0 => Tile::Void,
+                    1 => Tile::Bot,
+                    2..=5 => Tile::Wall,
+                    _ => Tile::Floor,
+                })
+                .collect();
+            let arm_cooling_down = random() < 2;
+            let heading = cfg!(feature = "compass").then(|| (random() % 4) as u8);
+
+            let r = (RADAR_SIZE / 2) as i8;
+            let mut observation = vec![Fix::ZERO; OBSERVATIONS];
+            shape::encode(
+                |x, y| tiles[(y + r) as usize * RADAR_SIZE + (x + r) as usize],
+                arm_cooling_down,
+                heading,
+                |index| observation[index] = Fix::ONE,
+            );
+            observation
+        };
+
+        return (0..SYNTHETIC_OBSERVATIONS).map(|_| observation()).collect();
+    };
+
+    let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(path);
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let observations = fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: couldn't read observations: {err}", path.display()));
+
+    observations
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let observation: Vec<_> = line
+                .split_whitespace()
+                .map(|x| Fix::from_num(x.parse::<f64>().unwrap_or_else(|err| panic!("{}:{}: {err}", path.display(), i + 1))))
+                .collect();
+
+            if observation.len() != OBSERVATIONS {
+                panic!("{}:{}: expected {OBSERVATIONS} values, got {}", path.display(), i + 1, observation.len());
+            }
+            if let Err(err) = check_observation(&observation) {
+                panic!("{}:{}: {err}", path.display(), i + 1);
+            }
+
+            observation
+        })
+        .collect()
+}
+
+/// Checks that `observation` could have come from the robot, going by
+/// `SCHEMA`.
+fn check_observation(observation: &[Fix]) -> Result<(), String> {
+    for feature in SCHEMA {
+        let values = &observation[feature.offset..feature.end()];
+        if let Some((i, x)) = values.iter().enumerate().find(|(_, &x)| x != Fix::ZERO && x != Fix::ONE) {
+            return Err(format!("`{}` value {i} is {x}, but observations are all zeros and ones", feature.name));
+        }
+
+        if let Encoding::Radar | Encoding::NorthUpRadar = feature.encoding {
+            let cells = RADAR_SIZE * RADAR_SIZE;
+            if let Some(cell) = (0..cells).find(|cell| (0..RADAR_CHANNELS).filter(|c| values[c * cells + cell] == Fix::ONE).count() > 1) {
+                return Err(format!("`{}` cell {cell} is one in more than one channel", feature.name));
+            }
+        }
+
+        if feature.encoding == Encoding::OneHot && values.iter().filter(|&&x| x == Fix::ONE).count() > 1 {
+            return Err(format!("`{}` has more than one value that's one", feature.name));
+        }
+    }
+    Ok(())
+}
+
+/// Writes `SCHEMA` as JSON to `KARTOFFEL_SCHEMA`, if it's set, for whatever
+/// trains the models to encode its observations the way the robot does and
+/// to put `SCHEMA_HASH` into the models.
+fn write_schema() {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_SCHEMA");
+
+    let Ok(path) = env::var("KARTOFFEL_SCHEMA") else {
+        return;
+    };
+    let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(path);
+
+    let tile_name = |tile| match tile {
+        Tile::Floor => "floor",
+        Tile::Wall => "wall",
+        Tile::Void => "void",
+        Tile::Bot => "bot",
+        Tile::Unknown => "unknown",
+    };
+    let quoted = |names: Vec<String>| names.iter().map(|name| format!("\"{name}\"")).collect::<Vec<_>>().join(", ");
+
+    // Unknown tiles are whatever the radar draws other than the known ones
+    let tiles: Vec<_> = [Tile::Floor, Tile::Wall, Tile::Void, Tile::Bot]
+        .into_iter()
+        .map(|tile| {
+            let chars = (' '..='~').filter(|&c| Tile::from_char(c) == tile).map(String::from).collect();
+            format!("\"{}\": [{}]", tile_name(tile), quoted(chars))
+        })
+        .collect();
+    let channels: Vec<_> = CHANNELS
+        .iter()
+        .map(|tiles| format!("[{}]", quoted(tiles.iter().map(|&tile| tile_name(tile).to_string()).collect())))
+        .collect();
+    let features: Vec<_> = SCHEMA
+        .iter()
+        .map(|feature| {
+            let encoding = match feature.encoding {
+                Encoding::Radar => "radar",
+                Encoding::Flag => "flag",
+                Encoding::OneHot => "one_hot",
+                Encoding::NorthUpRadar => "north_up_radar",
+            };
+            format!(
+                "    {{ \"name\": \"{}\", \"offset\": {}, \"len\": {}, \"encoding\": \"{encoding}\" }}",
+                feature.name, feature.offset, feature.len,
+            )
+        })
+        .collect();
+
+    let json = format!(
+        "{{\n\
+         \x20 \"hash\": {SCHEMA_HASH},\n\
+         \x20 \"observations\": {OBSERVATIONS},\n\
+         \x20 \"radar_size\": {RADAR_SIZE},\n\
+         \x20 \"tiles\": {{ {} }},\n\
+         \x20 \"channels\": [{}],\n\
+         \x20 \"features\": [\n{}\n  ]\n\
+         }}\n",
+        tiles.join(", "),
+        channels.join(", "),
+        features.join(",\n"),
+    );
+
+    fs::write(&path, json).unwrap_or_else(|err| panic!("{}: couldn't write schema: {err}", path.display()));
+}
+
+/// Checks that `SCHEMA`'s features follow one another and make up all of the
+/// observations.
+fn check_schema() {
+    let mut offset = 0;
+    for feature in SCHEMA {
+        assert!(feature.offset == offset, "`{}` starts at {}, but the feature before ends at {offset}", feature.name, feature.offset);
+        offset = feature.end();
+    }
+    assert!(offset == OBSERVATIONS, "SCHEMA's features make up {offset} observations, but there are {OBSERVATIONS}");
+}
+
+/// Converts every `Dense` layer into a `QDense` one - weights get a scale per
+/// neuron, inputs get a scale per layer, calibrated on `observations`.
+fn quantize(layers: &[ModelLayer], observations: &[Vec<Fix>]) -> Vec<ModelLayer> {
+    let overflows = AtomicU32::new(0);
+    let mut state = initial_state(layers);
+    let mut input_max = vec![0.0f64; layers.len()];
+    for observation in observations {
+        let outputs = forward(layers, observation, &mut state, &overflows);
+        for (i, input) in [observation].into_iter().chain(&outputs).enumerate().take(layers.len()) {
+            for x in input {
+                input_max[i] = input_max[i].max(x.to_num::<f64>().abs());
+            }
+        }
+    }
+
+    layers
+        .iter()
+        .zip(input_max)
+        .map(|(layer, input_max)| {
+            let Weights::Dense { weights, biases } = &layer.weights else {
+                return layer.clone();
+            };
+
+            let input_frac = if input_max == 0.0 {
+                7
+            } else {
+                (127.0 / input_max).log2().floor().clamp(0.0, Fix::FRAC_NBITS as f64) as u8
+            };
+
+            let mut qweights = Vec::new();
+            let mut multipliers = Vec::new();
+            let mut shifts = Vec::new();
+            for neuron_weights in weights {
+                let max = neuron_weights.iter().map(|w| w.to_num::<f64>().abs()).fold(0.0, f64::max);
+                if max == 0.0 {
+                    qweights.push(vec![0; layer.inputs]);
+                    multipliers.push(0);
+                    shifts.push(0);
+                    continue;
+                }
+
+                let scale = max / 127.0;
+                qweights.push(neuron_weights.iter().map(|w| (w.to_num::<f64>() / scale).round() as i8).collect());
+
+                // acc * scale / 2^input_frac is the pre-activation, so that
+                // times 2^FRAC_NBITS are its bits
+                let factor = scale * 2f64.powi(Fix::FRAC_NBITS as i32 - input_frac as i32);
+                let shift = (30 - factor.log2().floor() as i32).clamp(0, 63);
+                multipliers.push((factor * 2f64.powi(shift)).round().min(i32::MAX as f64) as i32);
+                shifts.push(shift as u8);
+            }
+
+            ModelLayer {
+                weights: Weights::QDense {
+                    input_frac,
+                    weights: qweights,
+                    multipliers,
+                    shifts,
+                    biases: biases.clone(),
+                },
+                ..*layer
+            }
+        })
+        .collect()
+}
+
+/// Converts `Dense` layers that are mostly zeros into `Sparse` ones, reporting
+/// how much ROM that saves - except for the ones taking the observations
+/// bit-packed, which only add up the weights of the cells that changed and so
+/// beat `Sparse`'s multiplications.
+fn sparsify(layers: &mut [ModelLayer]) {
+    let binary = binary_layers(layers);
+    let mut saved = 0;
+
+    for (i, layer) in layers.iter_mut().enumerate() {
+        let Weights::Dense { weights, biases } = &layer.weights else {
+            continue;
+        };
+
+        let nnz = weights.iter().flatten().filter(|&&w| w != Fix::ZERO).count();
+        let sparsity = 1.0 - nnz as f64 / (layer.inputs * layer.outputs) as f64;
+        if sparsity < SPARSITY_THRESHOLD || nnz > u16::MAX as usize {
+            continue;
+        }
+
+        let rows = weights
+            .iter()
+            .map(|row| (0..).zip(row.iter().copied()).filter(|&(_, w)| w != Fix::ZERO).collect())
+            .collect();
+
+        let sparse = ModelLayer {
+            weights: Weights::Sparse { rows, biases: biases.clone() },
+            ..*layer
+        };
+
+        let (dense_len, sparse_len) = (data_len(layer), data_len(&sparse));
+        if sparse_len >= dense_len {
+            continue;
+        }
+
+        if binary.contains(&i) {
+            println!(
+                "cargo:warning=layer {i} is {:.1}% zeros, but stays dense to take the observations bit-packed, forgoing {} bytes of ROM",
+                100.0 * sparsity,
+                dense_len - sparse_len,
+            );
+            continue;
+        }
+
+        println!(
+            "cargo:warning=layer {i} is {:.1}% zeros, storing it as sparse saves {} bytes of ROM",
+            100.0 * sparsity,
+            dense_len - sparse_len,
+        );
+        saved += dense_len - sparse_len;
+
+        *layer = sparse;
+    }
+
+    if saved > 0 {
+        println!("cargo:warning=sparse layers save {saved} bytes of ROM in total");
+    }
+}
+
+impl Term {
+    /// Code adding up the term, computing what `Term::eval` does.
+    fn code(&self) -> String {
+        let sign = |negate: bool| if negate { "-" } else { "" };
+
+        match *self {
+            Term::Mul { input, weight } => format!("((a[{input}] * {weight}) >> {})", Fix::FRAC_NBITS),
+            Term::Shr { input, negate, shift: 0 } => format!("{}a[{input}]", sign(negate)),
+            Term::Shr { input, negate, shift } => format!("({}a[{input}] >> {shift})", sign(negate)),
+            Term::Shl { input, negate, shift } => format!("({}a[{input}] << {shift})", sign(negate)),
+        }
+    }
+}
+
+/// Generates `Unrolled{index}`, a straight-line version of a `Dense` or
+/// `Sparse` layer; `None` for other kinds of layers.
+fn unroll(index: usize, layer: &ModelLayer) -> Option<String> {
+    let (rows, biases): (Vec<Vec<_>>, _) = match &layer.weights {
+        Weights::Dense { weights, biases } => {
+            let rows = weights.iter().map(|row| row.iter().enumerate().filter_map(|(j, &w)| Term::new(j, w)).collect());
+            (rows.collect(), biases)
+        }
+
+        Weights::Sparse { rows, biases } => {
+            let rows = rows.iter().map(|row| row.iter().filter_map(|&(j, w)| Term::new(j as usize, w)).collect());
+            (rows.collect(), biases)
+        }
+
+        _ => return None,
+    };
+
+    let terms = rows.iter().flatten();
+    let muls = terms.clone().filter(|term| matches!(term, Term::Mul { .. })).count();
+    let zeros = layer.inputs * layer.outputs - terms.count();
+    println!(
+        "cargo:warning=unrolled layer {index} does {muls} multiplications, {} shifts and skips {zeros} zero weights",
+        rows.iter().map(Vec::len).sum::<usize>() - muls,
+    );
+
+    let activation = activation_type(layer.activation, layer.activation_param);
+    let (inputs, outputs) = (layer.inputs, layer.outputs);
+
+    let neurons: String = rows
+        .iter()
+        .zip(biases)
+        .map(|(row, bias)| {
+            let sum: String = row.iter().map(|term| format!(" + {}", term.code())).collect();
+            format!("            activation.apply(narrow({}{sum}, &self.0)),\n", bias.to_bits())
+        })
+        .collect();
+
+    Some(format!(
+        "pub struct Unrolled{index}(core::sync::atomic::AtomicU32);\n\
+         \n\
+         impl Layer for Unrolled{index} {{\n\
+         \x20   type Input = [Fix; {inputs}];\n\
+         \x20   type Output = [Fix; {outputs}];\n\
+         \x20   type State = ();\n\
+         \n\
+         \x20   const INITIAL_STATE: () = ();\n\
+         \n\
+         \x20   fn forward(&self, input: &[Fix; {inputs}], _: &mut ()) -> [Fix; {outputs}] {{\n\
+         \x20       let a = input.map(|a| a.to_bits() as i64);\n\
+         \x20       let activation = <{activation} as Activation>::INSTANCE;\n\
+         \x20       [\n\
+         {neurons}\
+         \x20       ]\n\
+         \x20   }}\n\
+         \n\
+         \x20   fn overflows(&self, f: &mut dyn FnMut(u32)) {{\n\
+         \x20       f(self.0.load(core::sync::atomic::Ordering::Relaxed));\n\
+         \x20   }}\n\
+         }}\n\
+         \n",
+    ))
+}
+
+/// Turns `QDense` layers back into `Dense` ones with the weights their int8
+/// ones stand for (give or take `Fix`'s precision).
+fn dequantize(layers: &[ModelLayer]) -> Vec<ModelLayer> {
+    layers
+        .iter()
+        .map(|layer| {
+            let Weights::QDense { input_frac, weights, multipliers, shifts, biases } = &layer.weights else {
+                return layer.clone();
+            };
+
+            // the pre-activation's bits are (sum(x * 2^input_frac * w) * multiplier) >> shift
+            let weights = weights
+                .iter()
+                .zip(multipliers.iter().zip(shifts))
+                .map(|(row, (&multiplier, &shift))| {
+                    let scale = multiplier as f64 * 2f64.powi(*input_frac as i32 - shift as i32 - Fix::FRAC_NBITS as i32);
+                    row.iter().map(|&w| Fix::saturating_from_num(w as f64 * scale)).collect()
+                })
+                .collect();
+
+            ModelLayer {
+                weights: Weights::Dense { weights, biases: biases.clone() },
+                ..*layer
+            }
+        })
+        .collect()
+}
+
+/// Reports how far each layer's outputs on the robot end up from the model's
+/// weights run in f64, on `observations` fed one after another; errors add up
+/// from one layer to the next.
+fn report_error(reference: &[ModelLayer], layers: &[ModelLayer], observations: &[Vec<Fix>]) {
+    let overflows = AtomicU32::new(0);
+    let mut state = initial_state::<Fix>(layers);
+    let mut reference_state = initial_state::<f64>(reference);
+    let mut max_error = vec![0.0f64; layers.len()];
+    let mut total_error = vec![0.0f64; layers.len()];
+    let mut max_blend_error = 0.0f64;
+    let mut total_blend_error = 0.0f64;
+
+    for observation in observations {
+        let outputs = forward(layers, observation, &mut state, &overflows);
+        let reference_outputs = forward(reference, &cast::<f64>(observation), &mut reference_state, &overflows);
+
+        for (i, (output, reference_output)) in outputs.iter().zip(&reference_outputs).enumerate() {
+            for (x, reference_x) in output.iter().zip(reference_output) {
+                let error = (x.to_num::<f64>() - reference_x).abs();
+                max_error[i] = max_error[i].max(error);
+                total_error[i] += error;
+            }
+        }
+
+        let blended = policy(layers, &outputs, &overflows);
+        let reference_blended = policy(reference, &reference_outputs, &overflows);
+        for (x, reference_x) in blended.iter().zip(&reference_blended) {
+            let error = (x.to_num::<f64>() - reference_x).abs();
+            max_blend_error = max_blend_error.max(error);
+            total_blend_error += error;
+        }
+    }
+
+    for (i, layer) in layers.iter().enumerate() {
+        if let Weights::Residual { .. } | Weights::Head { .. } | Weights::Expert = layer.weights {
+            continue;
+        }
+        println!(
+            "cargo:warning=layer {i} is off from f64 by {:.6} at most and {:.6} on average",
+            max_error[i],
+            total_error[i] / (observations.len() * layer.outputs) as f64,
+        );
+    }
+
+    if !experts(layers).is_empty() {
+        println!(
+            "cargo:warning=blended experts are off from f64 by {:.6} at most and {:.6} on average",
+            max_blend_error,
+            total_blend_error / (observations.len() * ACTIONS) as f64,
+        );
+    }
+}
+
+fn unroll_requested() -> bool {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_UNROLL");
+
+    match env::var("KARTOFFEL_UNROLL").as_deref() {
+        Ok("1") => true,
+        Ok("0") | Err(_) => false,
+        Ok(other) => panic!("KARTOFFEL_UNROLL: expected `0` or `1`, got `{other}`"),
+    }
+}
+
+fn quantize_requested() -> bool {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_QUANTIZE");
+
+    match env::var("KARTOFFEL_QUANTIZE").as_deref() {
+        Ok("int8") => true,
+        Ok(other) => panic!("KARTOFFEL_QUANTIZE: unknown format `{other}`, expected `int8`"),
+        Err(_) => false,
+    }
+}
+
+/// How the robot gets to handle mirrored situations the same way.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mirror {
+    /// Wraps the model in `nn::Mirrored`.
+    Average,
+    /// The model got trained with tied weights, so it already does that.
+    Tied,
+}
+
+fn mirror_requested() -> Option<Mirror> {
+    println!("cargo:rerun-if-env-changed=KARTOFFEL_MIRROR");
+
+    let mirror = match env::var("KARTOFFEL_MIRROR").as_deref() {
+        Ok("average") => Some(Mirror::Average),
+        Ok("tied") => Some(Mirror::Tied),
+        Ok(other) => panic!("KARTOFFEL_MIRROR: expected `average` or `tied`, got `{other}`"),
+        Err(_) => None,
+    };
+
+    // A mirrored world has its east and west swapped too, which the mirroring
+    // doesn't know about
+    if mirror.is_some() && cfg!(feature = "compass") {
+        panic!("KARTOFFEL_MIRROR: can't mirror observations with a compass heading in them");
+    }
+
+    mirror
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/shape.rs");
+    println!("cargo:rerun-if-changed=src/kartoffel_nn.rs");
+    println!("cargo:rerun-if-changed=src/kartoffel_nn");
+
+    check_schema();
+    write_schema();
+
+    let models = models();
+    let observations = observations();
+    let quantize_requested = quantize_requested();
+    let unroll_requested = unroll_requested();
+    let mirror_requested = mirror_requested();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut code = String::new();
+    let mut rom = Vec::new();
+    for (name, path) in &models {
+        if models.len() > 1 {
+            println!("cargo:warning=model `{name}` ({}):", path.display());
+        }
+
+        let layers = build_model(path, &observations, quantize_requested);
+        fs::write(out_dir.join(format!("{name}.knn")), write_model(&layers)).unwrap();
+        code += &model_code(name, &layers, &observations, unroll_requested, mirror_requested);
+
+        // The blob itself only gets read at compile time, so what ends up in
+        // ROM is the layers' data
+        rom.push((name, layers.iter().map(data_len).sum::<usize>()));
+    }
+
+    println!(
+        "cargo:warning=models take {} bytes of ROM: {}",
+        rom.iter().map(|(_, bytes)| bytes).sum::<usize>(),
+        rom.iter().map(|(name, bytes)| format!("{name} {bytes}")).collect::<Vec<_>>().join(", "),
+    );
+
+    code += &registry_code(&models);
+
+    let identifiers: Vec<_> = code.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+    for layer_type in LAYER_TYPES.iter().filter(|layer_type| identifiers.contains(layer_type)) {
+        println!("cargo:rustc-cfg=kartoffel_layer=\"{layer_type}\"");
+    }
+
+    fs::write(out_dir.join("kartoffel_nn_model.rs"), code).unwrap();
+}
+
+/// Reads the model at `path` and turns it into the layers the robot runs,
+/// reporting on the way.
+fn build_model(path: &Path, observations: &[Vec<Fix>], quantize_requested: bool) -> Vec<ModelLayer> {
+    let blob = fs::read(path).unwrap_or_else(|err| panic!("{}: couldn't read model: {err}", path.display()));
+    let blob = upgrade(blob);
+    let mut layers = read_model(path, &blob);
+    let reference = dequantize(&layers);
+
+    if quantize_requested {
+        let quantized = quantize(&layers, observations);
+
+        let overflows = AtomicU32::new(0);
+        let qoverflows = AtomicU32::new(0);
+        let mut state = initial_state(&layers);
+        let mut qstate = initial_state(&quantized);
+        let agreeing = observations
+            .iter()
+            .filter(|observation| {
+                let action = argmax(&policy(&layers, &forward(&layers, observation, &mut state, &overflows), &overflows));
+                let qaction = argmax(&policy(&quantized, &forward(&quantized, observation, &mut qstate, &qoverflows), &qoverflows));
+                action == qaction
+            })
+            .count();
+
+        println!(
+            "cargo:warning=int8 model picks the same action as the Fix one for {agreeing}/{} observations ({:.1}%)",
+            observations.len(),
+            100.0 * agreeing as f64 / observations.len() as f64,
+        );
+
+        for (name, overflows) in [("Fix", overflows), ("int8", qoverflows)] {
+            let overflows = overflows.into_inner();
+            if overflows > 0 {
+                println!("cargo:warning={name} model overflowed {overflows} pre-activations on those observations");
+            }
+        }
+
+        layers = quantized;
+    }
+
+    sparsify(&mut layers);
+
+    // Only worth the noise for observations the robot has actually seen
+    if env::var_os("KARTOFFEL_OBSERVATIONS").is_some() {
+        report_error(&reference, &layers, observations);
+    }
+
+    layers
+}
+
+/// Generates module `name` with the model's type (`KartoffelNN`), its static
+/// (`KARTOFFEL_NN`), `heads` and the unrolled layers, if any.
+fn model_code(name: &str, layers: &[ModelLayer], observations: &[Vec<Fix>], unroll_requested: bool, mirror: Option<Mirror>) -> String {
+    let experts = experts(layers);
+    if mirror == Some(Mirror::Tied) {
+        check_tied(layers, observations);
+    }
+
+    let binary = binary_layers(layers);
+    let is_binary = |i| binary.contains(&i);
+
+    let mut unrolled = String::new();
+    let mut built = Vec::new();
+    for (i, layer) in layers.iter().enumerate() {
+        if let Weights::Residual { .. } | Weights::Head { .. } | Weights::Expert = layer.weights {
+            built.push(None);
+            continue;
+        }
+
+        let unrolled_layer = if unroll_requested && !is_binary(i) { unroll(i, layer) } else { None };
+
+        built.push(Some(if let Some(code) = unrolled_layer {
+            unrolled += &code;
+            (format!("Unrolled{i}"), format!("Unrolled{i}(core::sync::atomic::AtomicU32::new(0))"))
+        } else {
+            let layer_ty = if is_binary(i) { layer.binary_type_name() } else { layer.type_name() };
+            let layer_expr = format!("<{layer_ty}>::read(MODEL, {i})");
+            (layer_ty, layer_expr)
+        }));
+    }
+
+    let chain = |range| chain(layers, &built, range);
+
+    // Heads get forked off the trunk right to left, so the output is nested
+    // as `(head0, (head1, head2))`
+    let heads = heads(layers);
+    let (mut ty, mut expr) = chain(heads[heads.len() - 1].1.clone()).unwrap();
+    let mut pattern = format!("head{}", heads.len() - 1);
+    for (i, (_, head)) in heads.iter().enumerate().rev().skip(1) {
+        let (head_ty, head_expr) = chain(head.clone()).unwrap();
+        ty = format!("Fork<{head_ty}, {ty}>");
+        expr = format!("Fork({head_expr}, {expr})");
+        pattern = format!("(head{i}, {pattern})");
+    }
+    if let Some((trunk_ty, trunk_expr)) = chain(0..trunk_len(layers)) {
+        ty = format!("Chain<{trunk_ty}, {ty}>");
+        expr = format!("Chain({trunk_expr}, {expr})");
+    }
+
+    // Same for experts, which the gate blends into a policy head's output
+    if let Some(first) = experts.first() {
+        (ty, expr) = chain(experts[experts.len() - 1].clone()).unwrap();
+        for expert in experts.iter().rev().skip(1) {
+            let (expert_ty, expert_expr) = chain(expert.clone()).unwrap();
+            ty = format!("Fork<{expert_ty}, {ty}>");
+            expr = format!("Fork({expert_expr}, {expr})");
+        }
+
+        let (gate_ty, gate_expr) = chain(0..first.start - 1).unwrap();
+        ty = format!("Mixture<{gate_ty}, {ty}, {}, {ACTIONS}>", experts.len());
+        expr = format!("Mixture::new({gate_expr}, {expr})");
+    }
+
+    if mirror == Some(Mirror::Average) {
+        ty = format!("Mirrored<{ty}, {RADAR_SIZE}, {RADAR_CHANNELS}, {TURN_LEFT}, {TURN_RIGHT}>");
+        expr = format!("Mirrored({expr})");
+    }
+
+    let head = |role| {
+        heads.iter().position(|(r, _)| *r == role).map_or("None".to_string(), |i| format!("Some(head{i}[0])"))
+    };
+    let policy = heads.iter().position(|(role, _)| *role == format::HEAD_POLICY).unwrap();
+    let value = head(format::HEAD_VALUE);
+    let enemy_nearby = head(format::HEAD_ENEMY_NEARBY);
+    let inputs = layers[0].inputs;
+
+    let code = format!(
+        "// unused when all of the layers are unrolled\n\
+         #[allow(dead_code)]\n\
+         const MODEL: &[u8] = include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{name}.knn\"));\n\
+         pub type KartoffelNN = {ty};\n\
+         pub static KARTOFFEL_NN: KartoffelNN = {expr};\n\
+         pub const INPUTS: usize = {inputs};\n\
+         \n\
+         pub fn heads(output: &<KartoffelNN as Layer>::Output) -> Heads {{\n\
+         \x20   let {pattern} = output;\n\
+         \x20   Heads {{ policy: *head{policy}, value: {value}, enemy_nearby: {enemy_nearby} }}\n\
+         }}\n\
+         \n\
+         {unrolled}",
+    );
+
+    let code: String = code.lines().map(|line| if line.is_empty() { "\n".to_string() } else { format!("    {line}\n") }).collect();
+    format!("pub mod {name} {{\n    use super::*;\n\n{code}}}\n\n")
+}
+
+/// Layers that take the observations bit-packed, as `BinaryDense`s.
+///
+/// The robot's observations are just zeros and ones, so dense layers taking
+/// them can take them bit-packed and skip their multiplications - as long as
+/// all of them are dense, since the gate and experts share their input.
+fn binary_layers(layers: &[ModelLayer]) -> Vec<usize> {
+    let input_layers: Vec<_> = [0].into_iter().chain(experts(layers).iter().map(|expert| expert.start)).collect();
+    if input_layers.iter().all(|&i| matches!(layers.get(i).map(|layer| &layer.weights), Some(Weights::Dense { .. }))) {
+        input_layers
+    } else {
+        Vec::new()
+    }
+}
+
+/// Type and expression of the `Chain` of the layers in `range`, out of their
+/// `built` ones, with residual blocks wrapped in `Residual`s - `None` if there
+/// are no layers.
+fn chain(layers: &[ModelLayer], built: &[Option<(String, String)>], range: Range<usize>) -> Option<(String, String)> {
+    let mut chained: Option<(String, String)> = None;
+    let mut i = range.start;
+    while i < range.end {
+        let item = match layers[i].weights {
+            Weights::Residual { layers: len } => {
+                let (block_ty, block_expr) = chain(layers, built, i + 1..i + 1 + len).unwrap();
+                let item = (format!("Residual<{block_ty}, {}>", layers[i].outputs), format!("Residual::new({block_expr})"));
+                i += len;
+                Some(item)
+            }
+
+            _ => built[i].clone(),
+        };
+        i += 1;
+
+        if let Some((layer_ty, layer_expr)) = item {
+            chained = Some(match chained {
+                Some((ty, expr)) => (format!("Chain<{ty}, {layer_ty}>"), format!("Chain({expr}, {layer_expr})")),
+                None => (layer_ty, layer_expr),
+            });
+        }
+    }
+    chained
+}
+
+/// Generates `Models`, the `Registry` of all the models, each with a session.
+fn registry_code(models: &[(String, PathBuf)]) -> String {
+    let first = &models[0].0;
+    let mut fields = String::new();
+    let mut sessions = String::new();
+    let mut names = Vec::new();
+    let mut forwards = String::new();
+    let mut resets = String::new();
+    let mut overflows = String::new();
+    let mut inputs = String::new();
+    for (i, (name, _)) in models.iter().enumerate() {
+        fields += &format!("    {name}: Session<{name}::KartoffelNN>,\n");
+        sessions += &format!("            {name}: Session::new(&{name}::KARTOFFEL_NN),\n");
+        names.push(format!("\"{name}\""));
+        forwards += &format!("            {i} => {name}::heads(&self.{name}.forward(&BinaryInput::from_bitmask(bitmask))),\n");
+        resets += &format!("            {i} => self.{name}.reset(),\n");
+        overflows += &format!("        {name}::KARTOFFEL_NN.overflows(f);\n");
+        if i > 0 {
+            inputs += &format!("        assert!({name}::INPUTS == {first}::INPUTS, \"models take different numbers of inputs\");\n");
+        }
+    }
+
+    let names = names.join(", ");
+
+    format!(
+        "pub struct Models {{\n\
+         {fields}\
+         }}\n\
+         \n\
+         impl Models {{\n\
+         \x20   pub const fn new() -> Self {{\n\
+         \x20       Models {{\n\
+         {sessions}\
+         \x20       }}\n\
+         \x20   }}\n\
+         }}\n\
+         \n\
+         impl Registry for Models {{\n\
+         \x20   type Output = Heads;\n\
+         \n\
+         \x20   const NAMES: &'static [&'static str] = &[{names}];\n\
+         \n\
+         \x20   const INPUTS: usize = {{\n\
+         {inputs}\
+         \x20       {first}::INPUTS\n\
+         \x20   }};\n\
+         \n\
+         \x20   fn forward(&mut self, index: usize, bitmask: &[u32]) -> Heads {{\n\
+         \x20       match index {{\n\
+         {forwards}\
+         \x20           _ => panic!(\"there's no model #{{index}}\"),\n\
+         \x20       }}\n\
+         \x20   }}\n\
+         \n\
+         \x20   fn reset(&mut self, index: usize) {{\n\
+         \x20       match index {{\n\
+         {resets}\
+         \x20           _ => panic!(\"there's no model #{{index}}\"),\n\
+         \x20       }}\n\
+         \x20   }}\n\
+         \n\
+         \x20   fn overflows(&self, f: &mut dyn FnMut(u32)) {{\n\
+         {overflows}\
+         \x20   }}\n\
+         }}\n",
+    )
+}
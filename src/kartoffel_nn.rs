@@ -1,1714 +1,416 @@
+mod activation;
+mod binary;
+mod conv;
+mod elman;
+pub mod format;
+mod mirror;
+mod mixture;
+mod norm;
+mod num;
+mod registry;
+mod softmax;
+mod sparse;
+// Only build.rs unrolls layers
+#[cfg_attr(not(test), allow(dead_code))]
+pub mod unroll;
+
+pub use activation::*;
+pub use binary::*;
+#[cfg_attr(not(any(kartoffel_layer = "Conv2d", kartoffel_layer = "MaxPool2d")), allow(unused_imports))]
+pub use conv::*;
+#[cfg_attr(not(kartoffel_layer = "Elman"), allow(unused_imports))]
+pub use elman::*;
+#[cfg_attr(not(kartoffel_layer = "Mirrored"), allow(unused_imports))]
+pub use mirror::*;
+#[cfg_attr(not(kartoffel_layer = "Mixture"), allow(unused_imports))]
+pub use mixture::*;
+#[cfg_attr(not(kartoffel_layer = "LayerNorm"), allow(unused_imports))]
+pub use norm::*;
+pub use num::*;
+pub use registry::*;
+pub use softmax::*;
+#[cfg_attr(not(kartoffel_layer = "Sparse"), allow(unused_imports))]
+pub use sparse::*;
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use format::{Header, LayerDesc, Reader};
+
+#[cfg(not(any(feature = "fix-i16f16", feature = "fix-i8f24")))]
 pub type Fix = fixed::types::I10F22;
 
-struct KartoffelLayer<const LEN: usize, const PREV_LEN: usize> {
+#[cfg(feature = "fix-i16f16")]
+pub type Fix = fixed::types::I16F16;
+
+#[cfg(feature = "fix-i8f24")]
+pub type Fix = fixed::types::I8F24;
+
+#[cfg(all(feature = "fix-i16f16", feature = "fix-i8f24"))]
+compile_error!("features `fix-i16f16` and `fix-i8f24` are mutually exclusive");
+
+#[cfg(all(feature = "overflow-wrap", feature = "overflow-trap"))]
+compile_error!("features `overflow-wrap` and `overflow-trap` are mutually exclusive");
+
+/// What happens to a pre-activation that doesn't fit into `Fix`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    Saturate,
+    Wrap,
+    Trap,
+}
+
+pub const OVERFLOW: Overflow = if cfg!(feature = "overflow-wrap") {
+    Overflow::Wrap
+} else if cfg!(feature = "overflow-trap") {
+    Overflow::Trap
+} else {
+    Overflow::Saturate
+};
+
+pub trait Layer {
+    type Input;
+    type Output;
+
+    /// Carried from one `forward` call to the next - `()` for everything but
+    /// recurrent and incrementally updated layers.
+    type State;
+
+    const INITIAL_STATE: Self::State;
+
+    fn forward(&self, input: &Self::Input, state: &mut Self::State) -> Self::Output;
+
+    /// Calls `f` with the number of overflown pre-activations of each layer,
+    /// in order.
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        let _ = f;
+    }
+}
+
+/// A model together with the state of its recurrent and incrementally updated
+/// layers; feed it consecutive observations of a single robot.
+pub struct Session<L: Layer + 'static> {
+    model: &'static L,
+    state: L::State,
+}
+
+impl<L: Layer> Session<L> {
+    pub const fn new(model: &'static L) -> Self {
+        Session { model, state: L::INITIAL_STATE }
+    }
+
+    pub fn forward(&mut self, input: &L::Input) -> L::Output {
+        self.model.forward(input, &mut self.state)
+    }
+
+    /// Makes the model forget everything it has seen so far.
+    pub fn reset(&mut self) {
+        self.state = L::INITIAL_STATE;
+    }
+}
+
+/// Validates the `index`-th layer of the model against the type reading it
+/// and returns a reader positioned at that layer's data.
+const fn seek_layer<A: Activation>(blob: &[u8], index: usize, kind: u8, inputs: usize, outputs: usize) -> Reader<'_> {
+    let header = Header::read(blob);
+    assert!(header.int_bits as u32 == Fix::INT_NBITS, "model blob integer bits do not match Fix");
+    assert!(header.frac_bits as u32 == Fix::FRAC_NBITS, "model blob fractional bits do not match Fix");
+    assert!(index < header.layer_count, "model blob has too few layers");
+
+    let mut offset = header.data_offset();
+    let mut i = 0;
+    while i < index {
+        offset += LayerDesc::read(blob, i).data_len(blob, offset);
+        i += 1;
+    }
+
+    let desc = LayerDesc::read(blob, index);
+    assert!(desc.kind == kind, "model layer kind does not match its type");
+    assert!(desc.inputs == inputs, "model layer input width does not match its type");
+    assert!(desc.outputs == outputs, "model layer output width does not match its type");
+    assert!(desc.activation == A::ID, "model layer activation does not match its type");
+    assert!(desc.activation_param == A::PARAM, "model layer activation parameter does not match its type");
+
+    Reader::new(blob, offset)
+}
+
+const fn read_fixes<const LEN: usize>(data: &mut Reader) -> [Fix; LEN] {
+    let mut fixes = [Fix::ZERO; LEN];
+    let mut i = 0;
+    while i < LEN {
+        fixes[i] = Fix::from_bits(data.i32());
+        i += 1;
+    }
+    fixes
+}
+
+/// Reads a matrix stored row by row.
+const fn read_matrix<const ROWS: usize, const COLS: usize>(data: &mut Reader) -> [[Fix; COLS]; ROWS] {
+    let mut matrix = [[Fix::ZERO; COLS]; ROWS];
+    let mut i = 0;
+    while i < ROWS {
+        matrix[i] = read_fixes(data);
+        i += 1;
+    }
+    matrix
+}
+
+/// Pre-activation of a single neuron, see `Num::Wide`.
+pub fn neuron<T: Num>(activations: &[T], weights: &[T], bias: T) -> T::Wide {
+    activations.iter().zip(weights).fold(bias.widen(), |z, (&a, &w)| z + T::mul_wide(a, w))
+}
+
+/// Brings a pre-activation back into `Fix` according to `OVERFLOW`, counting
+/// the times it didn't fit.
+pub fn narrow(z: i64, overflows: &AtomicU32) -> Fix {
+    narrow_with(OVERFLOW, z, overflows)
+}
+
+/// `narrow` according to `overflow` instead - `build.rs` always saturates, so
+/// that it gets to report a model's overflows whatever the robot does about
+/// them.
+pub fn narrow_with(overflow: Overflow, z: i64, overflows: &AtomicU32) -> Fix {
+    if let Ok(z) = i32::try_from(z) {
+        return Fix::from_bits(z);
+    }
+
+    overflows.fetch_add(1, Ordering::Relaxed);
+
+    match overflow {
+        Overflow::Saturate => if z < 0 { Fix::MIN } else { Fix::MAX },
+        Overflow::Wrap => Fix::from_bits(z as i32),
+        Overflow::Trap => panic!("pre-activation overflowed Fix"),
+    }
+}
+
+/// Advances xorshift32 `state`, which mustn't be zero, and returns it - what
+/// the robot samples its moves with and `build.rs` makes up inputs with.
+pub fn xorshift(state: &mut u32) -> u32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 17;
+    *state ^= *state << 5;
+    *state
+}
+
+/// Rounds `x` to an i8 with `frac` fractional bits, saturating.
+pub fn quantize(x: Fix, frac: u8) -> i8 {
+    let shift = Fix::FRAC_NBITS - frac as u32;
+    let bits = if shift == 0 { x.to_bits() } else { (x.to_bits() >> (shift - 1)).wrapping_add(1) >> 1 };
+    bits.clamp(i8::MIN as i32, i8::MAX as i32) as i8
+}
+
+/// Pre-activation of a single int8 neuron, requantized into `Fix` bits widened
+/// to an i64.
+pub fn qneuron(inputs: &[i8], weights: &[i8], multiplier: i32, shift: u8, bias: Fix) -> i64 {
+    let acc: i32 = inputs.iter().zip(weights).map(|(&x, &w)| x as i32 * w as i32).sum();
+    ((acc as i64 * multiplier as i64) >> shift) + bias.to_bits() as i64
+}
+
+pub struct Dense<const PREV_LEN: usize, const LEN: usize, A> {
     weights: [[Fix; PREV_LEN]; LEN],
-    biases: [Fix; LEN]
+    biases: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
 }
 
-pub struct KartoffelNN {
-    layer0: KartoffelLayer<20, 50>,
-    layer1: KartoffelLayer<20, 20>,
-    layer2: KartoffelLayer<6, 20>,
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> Dense<PREV_LEN, LEN, A> {
+    /// Reads `index`-th layer of the model, failing const evaluation if its
+    /// shape or activation doesn't match this type.
+    #[cfg_attr(not(kartoffel_layer = "Dense"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_DENSE, PREV_LEN, LEN);
+        let weights = read_matrix(&mut data);
+        let biases = read_fixes(&mut data);
+
+        Dense { weights, biases, activation: A::INSTANCE, overflows: AtomicU32::new(0) }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> Layer for Dense<PREV_LEN, LEN, A> {
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], _: &mut ()) -> [Fix; LEN] {
+        let mut next_activations = [Fix::ZERO; LEN];
+        let mut next_activations_iter = next_activations.iter_mut();
+        for (neuron_weights, &neuron_bias) in self.weights.iter().zip(&self.biases) {
+            let z = narrow(neuron(activations, neuron_weights, neuron_bias), &self.overflows);
+            let a = self.activation.apply(z);
+            *next_activations_iter.next().expect("next_activations too small") = a
+        }
+        next_activations
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+/// Drop-in replacement for `Dense` with int8 weights and i32 accumulators;
+/// inputs get quantized on the way in, outputs are `Fix` again.
+pub struct QDense<const PREV_LEN: usize, const LEN: usize, A> {
+    input_frac: u8,
+    weights: [[i8; PREV_LEN]; LEN],
+    multipliers: [i32; LEN],
+    shifts: [u8; LEN],
+    biases: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
 }
 
-impl KartoffelNN {
-    pub fn forward(&self, input: [Fix; 50]) -> [Fix; 6] {
-        fn calc_layer<const LEN: usize, const PREV_LEN: usize>(activations: &[Fix], weights: &[[Fix; PREV_LEN]; LEN], biases: &[Fix; LEN], activation_fn: impl Fn(Fix) -> Fix) -> [Fix; LEN] {
-            let mut next_activations = [Fix::ZERO; LEN];
-            let mut next_activations_iter = next_activations.iter_mut();
-            for (neuron_weights, neuron_bias) in weights.iter().zip(biases) {
-                let z = activations.iter().zip(neuron_weights).map(|(a, w)| a * w).sum::<Fix>() + neuron_bias;
-                let a = activation_fn(z);
-                *next_activations_iter.next().expect("next_activations too small") = a
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> QDense<PREV_LEN, LEN, A> {
+    #[cfg_attr(not(kartoffel_layer = "QDense"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_QDENSE, PREV_LEN, LEN);
+
+        let input_frac = data.u8();
+        assert!(input_frac as u32 <= Fix::FRAC_NBITS, "model layer input_frac is out of range");
+
+        let mut weights = [[0; PREV_LEN]; LEN];
+        let mut i = 0;
+        while i < LEN {
+            let mut j = 0;
+            while j < PREV_LEN {
+                weights[i][j] = data.i8();
+                j += 1;
             }
-            next_activations
+            i += 1;
+        }
+
+        let mut multipliers = [0; LEN];
+        let mut i = 0;
+        while i < LEN {
+            multipliers[i] = data.i32();
+            i += 1;
+        }
+
+        let mut shifts = [0; LEN];
+        let mut i = 0;
+        while i < LEN {
+            shifts[i] = data.u8();
+            assert!(shifts[i] < 64, "model layer shift is out of range");
+            i += 1;
+        }
+
+        let biases = read_fixes(&mut data);
+
+        QDense {
+            input_frac,
+            weights,
+            multipliers,
+            shifts,
+            biases,
+            activation: A::INSTANCE,
+            overflows: AtomicU32::new(0),
+        }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> Layer for QDense<PREV_LEN, LEN, A> {
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], _: &mut ()) -> [Fix; LEN] {
+        let inputs = activations.map(|a| quantize(a, self.input_frac));
+
+        let mut next_activations = [Fix::ZERO; LEN];
+        for (i, a) in next_activations.iter_mut().enumerate() {
+            let z = qneuron(&inputs, &self.weights[i], self.multipliers[i], self.shifts[i], self.biases[i]);
+            *a = self.activation.apply(narrow(z, &self.overflows));
         }
+        next_activations
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+/// Feeds the output of `A` into `B`; nest it to build deeper networks, e.g.
+/// `Chain<Chain<Dense<50, 20, Relu>, Dense<20, 20, Relu>>, Dense<20, 6, Id>>`.
+pub struct Chain<A, B>(pub A, pub B);
+
+impl<A, B> Layer for Chain<A, B>
+where
+    A: Layer,
+    B: Layer<Input = A::Output>,
+{
+    type Input = A::Input;
+    type Output = B::Output;
+    type State = (A::State, B::State);
+
+    const INITIAL_STATE: Self::State = (A::INITIAL_STATE, B::INITIAL_STATE);
 
-        fn id(f: Fix) -> Fix { f }
-        fn relu(x: Fix) -> Fix { x.max(Fix::ZERO) }
+    fn forward(&self, input: &A::Input, state: &mut Self::State) -> B::Output {
+        self.1.forward(&self.0.forward(input, &mut state.0), &mut state.1)
+    }
 
-        let activations = input;
-        let activations = calc_layer(&activations, &self.layer0.weights, &self.layer0.biases, relu);
-        let activations = calc_layer(&activations, &self.layer1.weights, &self.layer1.biases, relu);
-        calc_layer(&activations, &self.layer2.weights, &self.layer2.biases, id)
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        self.0.overflows(f);
+        self.1.overflows(f);
     }
 }
 
-pub static KARTOFFEL_NN: KartoffelNN = KartoffelNN {
-	layer0: KartoffelLayer {
-    weights: [
-        [
-            Fix::from_le_bytes([172, 55, 229, 255]),
-            Fix::from_le_bytes([128, 60, 205, 255]),
-            Fix::from_le_bytes([112, 178, 72, 0]),
-            Fix::from_le_bytes([195, 149, 246, 255]),
-            Fix::from_le_bytes([54, 38, 23, 0]),
-            Fix::from_le_bytes([42, 39, 2, 0]),
-            Fix::from_le_bytes([16, 112, 198, 255]),
-            Fix::from_le_bytes([218, 198, 27, 0]),
-            Fix::from_le_bytes([1, 103, 252, 255]),
-            Fix::from_le_bytes([20, 134, 3, 0]),
-            Fix::from_le_bytes([80, 142, 204, 255]),
-            Fix::from_le_bytes([102, 217, 250, 255]),
-            Fix::from_le_bytes([26, 22, 28, 0]),
-            Fix::from_le_bytes([82, 185, 186, 255]),
-            Fix::from_le_bytes([8, 42, 250, 255]),
-            Fix::from_le_bytes([2, 40, 204, 255]),
-            Fix::from_le_bytes([162, 129, 62, 0]),
-            Fix::from_le_bytes([171, 47, 40, 0]),
-            Fix::from_le_bytes([238, 250, 42, 0]),
-            Fix::from_le_bytes([119, 188, 235, 255]),
-            Fix::from_le_bytes([85, 173, 223, 255]),
-            Fix::from_le_bytes([188, 213, 241, 255]),
-            Fix::from_le_bytes([112, 184, 226, 255]),
-            Fix::from_le_bytes([228, 63, 210, 255]),
-            Fix::from_le_bytes([82, 74, 36, 0]),
-            Fix::from_le_bytes([52, 0, 206, 255]),
-            Fix::from_le_bytes([21, 3, 214, 255]),
-            Fix::from_le_bytes([209, 20, 8, 0]),
-            Fix::from_le_bytes([171, 47, 245, 255]),
-            Fix::from_le_bytes([25, 123, 19, 0]),
-            Fix::from_le_bytes([127, 173, 13, 0]),
-            Fix::from_le_bytes([187, 206, 223, 255]),
-            Fix::from_le_bytes([212, 167, 213, 255]),
-            Fix::from_le_bytes([210, 248, 250, 255]),
-            Fix::from_le_bytes([43, 84, 253, 255]),
-            Fix::from_le_bytes([196, 253, 214, 255]),
-            Fix::from_le_bytes([143, 161, 5, 0]),
-            Fix::from_le_bytes([189, 90, 221, 255]),
-            Fix::from_le_bytes([149, 185, 235, 255]),
-            Fix::from_le_bytes([97, 112, 227, 255]),
-            Fix::from_le_bytes([16, 230, 198, 255]),
-            Fix::from_le_bytes([65, 116, 240, 255]),
-            Fix::from_le_bytes([151, 247, 22, 0]),
-            Fix::from_le_bytes([55, 209, 31, 0]),
-            Fix::from_le_bytes([125, 207, 252, 255]),
-            Fix::from_le_bytes([169, 21, 67, 0]),
-            Fix::from_le_bytes([60, 56, 32, 0]),
-            Fix::from_le_bytes([215, 217, 237, 255]),
-            Fix::from_le_bytes([119, 207, 25, 0]),
-            Fix::from_le_bytes([125, 26, 9, 0]),
-        ],
-        [
-            Fix::from_le_bytes([248, 92, 2, 0]),
-            Fix::from_le_bytes([195, 249, 210, 255]),
-            Fix::from_le_bytes([219, 200, 250, 255]),
-            Fix::from_le_bytes([207, 43, 254, 255]),
-            Fix::from_le_bytes([37, 81, 246, 255]),
-            Fix::from_le_bytes([208, 182, 222, 255]),
-            Fix::from_le_bytes([64, 252, 15, 0]),
-            Fix::from_le_bytes([12, 49, 13, 0]),
-            Fix::from_le_bytes([42, 140, 213, 255]),
-            Fix::from_le_bytes([235, 233, 238, 255]),
-            Fix::from_le_bytes([228, 107, 246, 255]),
-            Fix::from_le_bytes([153, 107, 11, 0]),
-            Fix::from_le_bytes([100, 193, 249, 255]),
-            Fix::from_le_bytes([13, 157, 226, 255]),
-            Fix::from_le_bytes([207, 195, 44, 0]),
-            Fix::from_le_bytes([41, 37, 253, 255]),
-            Fix::from_le_bytes([26, 233, 183, 255]),
-            Fix::from_le_bytes([198, 124, 8, 0]),
-            Fix::from_le_bytes([232, 116, 228, 255]),
-            Fix::from_le_bytes([246, 31, 218, 255]),
-            Fix::from_le_bytes([159, 80, 249, 255]),
-            Fix::from_le_bytes([190, 47, 18, 0]),
-            Fix::from_le_bytes([190, 162, 21, 0]),
-            Fix::from_le_bytes([54, 123, 213, 255]),
-            Fix::from_le_bytes([180, 111, 30, 0]),
-            Fix::from_le_bytes([10, 18, 29, 0]),
-            Fix::from_le_bytes([79, 122, 16, 0]),
-            Fix::from_le_bytes([210, 233, 216, 255]),
-            Fix::from_le_bytes([193, 201, 25, 0]),
-            Fix::from_le_bytes([36, 218, 231, 255]),
-            Fix::from_le_bytes([81, 134, 1, 0]),
-            Fix::from_le_bytes([113, 69, 4, 0]),
-            Fix::from_le_bytes([173, 27, 217, 255]),
-            Fix::from_le_bytes([212, 154, 235, 255]),
-            Fix::from_le_bytes([233, 235, 245, 255]),
-            Fix::from_le_bytes([70, 185, 253, 255]),
-            Fix::from_le_bytes([63, 138, 255, 255]),
-            Fix::from_le_bytes([19, 3, 27, 0]),
-            Fix::from_le_bytes([175, 142, 221, 255]),
-            Fix::from_le_bytes([223, 46, 8, 0]),
-            Fix::from_le_bytes([220, 8, 241, 255]),
-            Fix::from_le_bytes([3, 108, 38, 0]),
-            Fix::from_le_bytes([115, 188, 239, 255]),
-            Fix::from_le_bytes([86, 189, 28, 0]),
-            Fix::from_le_bytes([98, 227, 17, 0]),
-            Fix::from_le_bytes([186, 248, 29, 0]),
-            Fix::from_le_bytes([182, 255, 3, 0]),
-            Fix::from_le_bytes([8, 16, 13, 0]),
-            Fix::from_le_bytes([190, 215, 224, 255]),
-            Fix::from_le_bytes([167, 222, 237, 255]),
-        ],
-        [
-            Fix::from_le_bytes([109, 137, 14, 0]),
-            Fix::from_le_bytes([243, 202, 7, 0]),
-            Fix::from_le_bytes([187, 156, 16, 0]),
-            Fix::from_le_bytes([31, 236, 50, 0]),
-            Fix::from_le_bytes([86, 112, 0, 0]),
-            Fix::from_le_bytes([110, 182, 244, 255]),
-            Fix::from_le_bytes([154, 47, 7, 0]),
-            Fix::from_le_bytes([81, 69, 51, 0]),
-            Fix::from_le_bytes([97, 10, 18, 0]),
-            Fix::from_le_bytes([224, 138, 199, 255]),
-            Fix::from_le_bytes([150, 176, 57, 0]),
-            Fix::from_le_bytes([156, 55, 40, 0]),
-            Fix::from_le_bytes([200, 50, 14, 0]),
-            Fix::from_le_bytes([24, 154, 15, 0]),
-            Fix::from_le_bytes([95, 21, 235, 255]),
-            Fix::from_le_bytes([37, 152, 13, 0]),
-            Fix::from_le_bytes([189, 137, 61, 0]),
-            Fix::from_le_bytes([232, 5, 81, 0]),
-            Fix::from_le_bytes([10, 40, 108, 0]),
-            Fix::from_le_bytes([140, 88, 2, 0]),
-            Fix::from_le_bytes([3, 206, 248, 255]),
-            Fix::from_le_bytes([127, 29, 219, 255]),
-            Fix::from_le_bytes([95, 233, 253, 255]),
-            Fix::from_le_bytes([241, 90, 202, 255]),
-            Fix::from_le_bytes([8, 219, 24, 0]),
-            Fix::from_le_bytes([229, 65, 205, 255]),
-            Fix::from_le_bytes([68, 103, 249, 255]),
-            Fix::from_le_bytes([154, 152, 7, 0]),
-            Fix::from_le_bytes([191, 17, 243, 255]),
-            Fix::from_le_bytes([48, 241, 24, 0]),
-            Fix::from_le_bytes([74, 20, 11, 0]),
-            Fix::from_le_bytes([99, 137, 244, 255]),
-            Fix::from_le_bytes([150, 227, 216, 255]),
-            Fix::from_le_bytes([121, 208, 69, 0]),
-            Fix::from_le_bytes([229, 107, 248, 255]),
-            Fix::from_le_bytes([155, 25, 32, 0]),
-            Fix::from_le_bytes([7, 136, 21, 0]),
-            Fix::from_le_bytes([160, 63, 7, 0]),
-            Fix::from_le_bytes([113, 225, 230, 255]),
-            Fix::from_le_bytes([140, 207, 244, 255]),
-            Fix::from_le_bytes([127, 154, 240, 255]),
-            Fix::from_le_bytes([162, 77, 253, 255]),
-            Fix::from_le_bytes([131, 54, 19, 0]),
-            Fix::from_le_bytes([226, 140, 12, 0]),
-            Fix::from_le_bytes([89, 92, 251, 255]),
-            Fix::from_le_bytes([112, 245, 228, 255]),
-            Fix::from_le_bytes([93, 173, 241, 255]),
-            Fix::from_le_bytes([2, 223, 230, 255]),
-            Fix::from_le_bytes([155, 88, 247, 255]),
-            Fix::from_le_bytes([65, 134, 96, 255]),
-        ],
-        [
-            Fix::from_le_bytes([18, 166, 13, 0]),
-            Fix::from_le_bytes([176, 118, 25, 0]),
-            Fix::from_le_bytes([37, 1, 1, 0]),
-            Fix::from_le_bytes([174, 38, 205, 255]),
-            Fix::from_le_bytes([134, 227, 36, 0]),
-            Fix::from_le_bytes([19, 173, 10, 0]),
-            Fix::from_le_bytes([158, 107, 11, 0]),
-            Fix::from_le_bytes([86, 34, 22, 0]),
-            Fix::from_le_bytes([205, 164, 12, 0]),
-            Fix::from_le_bytes([227, 57, 224, 255]),
-            Fix::from_le_bytes([168, 73, 174, 255]),
-            Fix::from_le_bytes([22, 109, 1, 0]),
-            Fix::from_le_bytes([69, 113, 195, 255]),
-            Fix::from_le_bytes([162, 38, 239, 255]),
-            Fix::from_le_bytes([75, 97, 242, 255]),
-            Fix::from_le_bytes([103, 71, 7, 0]),
-            Fix::from_le_bytes([104, 77, 190, 255]),
-            Fix::from_le_bytes([64, 52, 173, 255]),
-            Fix::from_le_bytes([142, 15, 216, 255]),
-            Fix::from_le_bytes([230, 227, 17, 0]),
-            Fix::from_le_bytes([249, 205, 250, 255]),
-            Fix::from_le_bytes([214, 47, 4, 0]),
-            Fix::from_le_bytes([38, 233, 253, 255]),
-            Fix::from_le_bytes([41, 194, 60, 0]),
-            Fix::from_le_bytes([99, 233, 234, 255]),
-            Fix::from_le_bytes([141, 220, 39, 0]),
-            Fix::from_le_bytes([95, 110, 8, 0]),
-            Fix::from_le_bytes([132, 125, 33, 0]),
-            Fix::from_le_bytes([44, 12, 52, 0]),
-            Fix::from_le_bytes([234, 22, 24, 0]),
-            Fix::from_le_bytes([61, 226, 253, 255]),
-            Fix::from_le_bytes([154, 32, 51, 0]),
-            Fix::from_le_bytes([136, 172, 17, 0]),
-            Fix::from_le_bytes([61, 193, 218, 255]),
-            Fix::from_le_bytes([88, 137, 253, 255]),
-            Fix::from_le_bytes([188, 89, 24, 0]),
-            Fix::from_le_bytes([56, 226, 23, 0]),
-            Fix::from_le_bytes([225, 151, 32, 0]),
-            Fix::from_le_bytes([245, 6, 253, 255]),
-            Fix::from_le_bytes([70, 79, 27, 0]),
-            Fix::from_le_bytes([169, 161, 228, 255]),
-            Fix::from_le_bytes([202, 171, 29, 0]),
-            Fix::from_le_bytes([31, 171, 25, 0]),
-            Fix::from_le_bytes([207, 194, 26, 0]),
-            Fix::from_le_bytes([130, 6, 221, 255]),
-            Fix::from_le_bytes([137, 94, 13, 0]),
-            Fix::from_le_bytes([69, 57, 223, 255]),
-            Fix::from_le_bytes([238, 45, 229, 255]),
-            Fix::from_le_bytes([86, 105, 240, 255]),
-            Fix::from_le_bytes([249, 160, 64, 0]),
-        ],
-        [
-            Fix::from_le_bytes([7, 66, 249, 255]),
-            Fix::from_le_bytes([157, 250, 239, 255]),
-            Fix::from_le_bytes([74, 96, 239, 255]),
-            Fix::from_le_bytes([96, 236, 230, 255]),
-            Fix::from_le_bytes([82, 242, 29, 0]),
-            Fix::from_le_bytes([38, 191, 0, 0]),
-            Fix::from_le_bytes([172, 152, 251, 255]),
-            Fix::from_le_bytes([68, 89, 1, 0]),
-            Fix::from_le_bytes([82, 120, 17, 0]),
-            Fix::from_le_bytes([127, 234, 3, 0]),
-            Fix::from_le_bytes([144, 107, 83, 0]),
-            Fix::from_le_bytes([172, 42, 3, 0]),
-            Fix::from_le_bytes([73, 217, 254, 255]),
-            Fix::from_le_bytes([164, 143, 43, 0]),
-            Fix::from_le_bytes([169, 146, 43, 0]),
-            Fix::from_le_bytes([100, 192, 105, 0]),
-            Fix::from_le_bytes([171, 97, 88, 0]),
-            Fix::from_le_bytes([4, 59, 68, 0]),
-            Fix::from_le_bytes([120, 6, 87, 0]),
-            Fix::from_le_bytes([116, 123, 232, 255]),
-            Fix::from_le_bytes([178, 121, 1, 0]),
-            Fix::from_le_bytes([87, 179, 54, 0]),
-            Fix::from_le_bytes([221, 131, 45, 0]),
-            Fix::from_le_bytes([74, 140, 43, 0]),
-            Fix::from_le_bytes([32, 81, 41, 0]),
-            Fix::from_le_bytes([167, 93, 241, 255]),
-            Fix::from_le_bytes([210, 49, 56, 0]),
-            Fix::from_le_bytes([117, 64, 22, 0]),
-            Fix::from_le_bytes([72, 50, 196, 255]),
-            Fix::from_le_bytes([251, 170, 209, 255]),
-            Fix::from_le_bytes([147, 36, 254, 255]),
-            Fix::from_le_bytes([62, 105, 227, 255]),
-            Fix::from_le_bytes([246, 190, 198, 255]),
-            Fix::from_le_bytes([220, 131, 216, 255]),
-            Fix::from_le_bytes([192, 240, 228, 255]),
-            Fix::from_le_bytes([70, 178, 247, 255]),
-            Fix::from_le_bytes([151, 21, 203, 255]),
-            Fix::from_le_bytes([170, 134, 19, 0]),
-            Fix::from_le_bytes([98, 192, 252, 255]),
-            Fix::from_le_bytes([126, 226, 228, 255]),
-            Fix::from_le_bytes([141, 169, 33, 0]),
-            Fix::from_le_bytes([146, 94, 196, 255]),
-            Fix::from_le_bytes([99, 118, 18, 0]),
-            Fix::from_le_bytes([165, 66, 2, 0]),
-            Fix::from_le_bytes([62, 255, 23, 0]),
-            Fix::from_le_bytes([18, 250, 205, 255]),
-            Fix::from_le_bytes([239, 182, 50, 0]),
-            Fix::from_le_bytes([101, 75, 8, 0]),
-            Fix::from_le_bytes([159, 81, 228, 255]),
-            Fix::from_le_bytes([197, 120, 210, 255]),
-        ],
-        [
-            Fix::from_le_bytes([150, 243, 233, 255]),
-            Fix::from_le_bytes([234, 81, 229, 255]),
-            Fix::from_le_bytes([6, 157, 16, 0]),
-            Fix::from_le_bytes([193, 9, 244, 255]),
-            Fix::from_le_bytes([100, 213, 91, 0]),
-            Fix::from_le_bytes([154, 171, 237, 255]),
-            Fix::from_le_bytes([162, 145, 20, 0]),
-            Fix::from_le_bytes([188, 123, 241, 255]),
-            Fix::from_le_bytes([44, 63, 16, 0]),
-            Fix::from_le_bytes([28, 220, 58, 0]),
-            Fix::from_le_bytes([190, 52, 195, 255]),
-            Fix::from_le_bytes([132, 109, 92, 0]),
-            Fix::from_le_bytes([54, 149, 26, 0]),
-            Fix::from_le_bytes([170, 72, 214, 255]),
-            Fix::from_le_bytes([213, 76, 6, 0]),
-            Fix::from_le_bytes([246, 144, 193, 255]),
-            Fix::from_le_bytes([62, 170, 233, 255]),
-            Fix::from_le_bytes([103, 37, 235, 255]),
-            Fix::from_le_bytes([41, 7, 198, 255]),
-            Fix::from_le_bytes([152, 87, 237, 255]),
-            Fix::from_le_bytes([189, 185, 217, 255]),
-            Fix::from_le_bytes([68, 240, 205, 255]),
-            Fix::from_le_bytes([58, 240, 246, 255]),
-            Fix::from_le_bytes([185, 149, 210, 255]),
-            Fix::from_le_bytes([127, 37, 17, 0]),
-            Fix::from_le_bytes([58, 206, 189, 255]),
-            Fix::from_le_bytes([65, 125, 199, 255]),
-            Fix::from_le_bytes([214, 106, 242, 255]),
-            Fix::from_le_bytes([104, 22, 220, 255]),
-            Fix::from_le_bytes([92, 104, 248, 255]),
-            Fix::from_le_bytes([111, 95, 238, 255]),
-            Fix::from_le_bytes([232, 226, 18, 0]),
-            Fix::from_le_bytes([8, 219, 4, 0]),
-            Fix::from_le_bytes([128, 248, 215, 255]),
-            Fix::from_le_bytes([140, 12, 227, 255]),
-            Fix::from_le_bytes([93, 23, 192, 255]),
-            Fix::from_le_bytes([33, 144, 232, 255]),
-            Fix::from_le_bytes([216, 124, 228, 255]),
-            Fix::from_le_bytes([2, 137, 57, 0]),
-            Fix::from_le_bytes([48, 23, 232, 255]),
-            Fix::from_le_bytes([40, 128, 210, 255]),
-            Fix::from_le_bytes([220, 238, 247, 255]),
-            Fix::from_le_bytes([151, 238, 242, 255]),
-            Fix::from_le_bytes([11, 4, 234, 255]),
-            Fix::from_le_bytes([252, 69, 210, 255]),
-            Fix::from_le_bytes([220, 38, 209, 255]),
-            Fix::from_le_bytes([60, 202, 198, 255]),
-            Fix::from_le_bytes([86, 83, 212, 255]),
-            Fix::from_le_bytes([194, 130, 196, 255]),
-            Fix::from_le_bytes([169, 210, 252, 255]),
-        ],
-        [
-            Fix::from_le_bytes([195, 223, 25, 0]),
-            Fix::from_le_bytes([66, 233, 44, 0]),
-            Fix::from_le_bytes([30, 216, 71, 0]),
-            Fix::from_le_bytes([72, 204, 203, 255]),
-            Fix::from_le_bytes([218, 32, 69, 0]),
-            Fix::from_le_bytes([12, 174, 253, 255]),
-            Fix::from_le_bytes([150, 56, 10, 0]),
-            Fix::from_le_bytes([84, 171, 17, 0]),
-            Fix::from_le_bytes([15, 4, 56, 0]),
-            Fix::from_le_bytes([180, 253, 75, 0]),
-            Fix::from_le_bytes([29, 67, 157, 255]),
-            Fix::from_le_bytes([77, 17, 48, 0]),
-            Fix::from_le_bytes([130, 133, 56, 0]),
-            Fix::from_le_bytes([126, 57, 1, 0]),
-            Fix::from_le_bytes([134, 37, 234, 255]),
-            Fix::from_le_bytes([223, 188, 213, 255]),
-            Fix::from_le_bytes([239, 81, 165, 255]),
-            Fix::from_le_bytes([254, 192, 181, 255]),
-            Fix::from_le_bytes([240, 185, 128, 255]),
-            Fix::from_le_bytes([234, 59, 172, 255]),
-            Fix::from_le_bytes([220, 181, 223, 255]),
-            Fix::from_le_bytes([92, 154, 51, 0]),
-            Fix::from_le_bytes([26, 180, 67, 0]),
-            Fix::from_le_bytes([18, 144, 36, 0]),
-            Fix::from_le_bytes([248, 138, 228, 255]),
-            Fix::from_le_bytes([147, 111, 211, 255]),
-            Fix::from_le_bytes([132, 25, 34, 0]),
-            Fix::from_le_bytes([252, 228, 191, 255]),
-            Fix::from_le_bytes([224, 108, 3, 0]),
-            Fix::from_le_bytes([170, 72, 197, 255]),
-            Fix::from_le_bytes([132, 66, 191, 255]),
-            Fix::from_le_bytes([129, 213, 13, 0]),
-            Fix::from_le_bytes([76, 241, 210, 255]),
-            Fix::from_le_bytes([162, 64, 191, 255]),
-            Fix::from_le_bytes([145, 129, 1, 0]),
-            Fix::from_le_bytes([202, 254, 209, 255]),
-            Fix::from_le_bytes([102, 109, 221, 255]),
-            Fix::from_le_bytes([117, 147, 207, 255]),
-            Fix::from_le_bytes([225, 178, 35, 0]),
-            Fix::from_le_bytes([153, 26, 236, 255]),
-            Fix::from_le_bytes([182, 248, 188, 255]),
-            Fix::from_le_bytes([228, 87, 210, 255]),
-            Fix::from_le_bytes([172, 141, 188, 255]),
-            Fix::from_le_bytes([106, 15, 189, 255]),
-            Fix::from_le_bytes([20, 17, 188, 255]),
-            Fix::from_le_bytes([103, 96, 158, 255]),
-            Fix::from_le_bytes([192, 169, 186, 255]),
-            Fix::from_le_bytes([106, 115, 180, 255]),
-            Fix::from_le_bytes([155, 132, 193, 255]),
-            Fix::from_le_bytes([97, 56, 36, 0]),
-        ],
-        [
-            Fix::from_le_bytes([77, 173, 247, 255]),
-            Fix::from_le_bytes([54, 200, 7, 0]),
-            Fix::from_le_bytes([65, 24, 236, 255]),
-            Fix::from_le_bytes([148, 200, 255, 255]),
-            Fix::from_le_bytes([127, 107, 231, 255]),
-            Fix::from_le_bytes([187, 166, 231, 255]),
-            Fix::from_le_bytes([221, 53, 237, 255]),
-            Fix::from_le_bytes([124, 212, 234, 255]),
-            Fix::from_le_bytes([246, 84, 230, 255]),
-            Fix::from_le_bytes([18, 43, 209, 255]),
-            Fix::from_le_bytes([0, 134, 208, 255]),
-            Fix::from_le_bytes([190, 169, 212, 255]),
-            Fix::from_le_bytes([68, 191, 244, 255]),
-            Fix::from_le_bytes([121, 9, 229, 255]),
-            Fix::from_le_bytes([8, 164, 48, 0]),
-            Fix::from_le_bytes([58, 43, 4, 0]),
-            Fix::from_le_bytes([196, 170, 53, 0]),
-            Fix::from_le_bytes([208, 185, 232, 255]),
-            Fix::from_le_bytes([220, 161, 17, 0]),
-            Fix::from_le_bytes([85, 85, 253, 255]),
-            Fix::from_le_bytes([136, 252, 42, 0]),
-            Fix::from_le_bytes([13, 184, 238, 255]),
-            Fix::from_le_bytes([41, 76, 238, 255]),
-            Fix::from_le_bytes([162, 216, 204, 255]),
-            Fix::from_le_bytes([17, 102, 204, 255]),
-            Fix::from_le_bytes([121, 194, 43, 0]),
-            Fix::from_le_bytes([118, 248, 206, 255]),
-            Fix::from_le_bytes([143, 129, 225, 255]),
-            Fix::from_le_bytes([169, 121, 3, 0]),
-            Fix::from_le_bytes([8, 54, 218, 255]),
-            Fix::from_le_bytes([17, 17, 29, 0]),
-            Fix::from_le_bytes([44, 77, 251, 255]),
-            Fix::from_le_bytes([206, 196, 60, 0]),
-            Fix::from_le_bytes([144, 2, 45, 0]),
-            Fix::from_le_bytes([146, 178, 2, 0]),
-            Fix::from_le_bytes([144, 203, 11, 0]),
-            Fix::from_le_bytes([141, 161, 60, 0]),
-            Fix::from_le_bytes([200, 181, 61, 0]),
-            Fix::from_le_bytes([246, 66, 220, 255]),
-            Fix::from_le_bytes([136, 64, 50, 0]),
-            Fix::from_le_bytes([178, 147, 31, 0]),
-            Fix::from_le_bytes([232, 130, 229, 255]),
-            Fix::from_le_bytes([57, 134, 226, 255]),
-            Fix::from_le_bytes([94, 247, 42, 0]),
-            Fix::from_le_bytes([39, 88, 3, 0]),
-            Fix::from_le_bytes([6, 29, 48, 0]),
-            Fix::from_le_bytes([153, 156, 21, 0]),
-            Fix::from_le_bytes([16, 153, 212, 255]),
-            Fix::from_le_bytes([179, 239, 227, 255]),
-            Fix::from_le_bytes([43, 20, 34, 0]),
-        ],
-        [
-            Fix::from_le_bytes([197, 143, 250, 255]),
-            Fix::from_le_bytes([248, 162, 243, 255]),
-            Fix::from_le_bytes([106, 225, 3, 0]),
-            Fix::from_le_bytes([165, 141, 33, 0]),
-            Fix::from_le_bytes([47, 79, 229, 255]),
-            Fix::from_le_bytes([54, 175, 14, 0]),
-            Fix::from_le_bytes([44, 95, 10, 0]),
-            Fix::from_le_bytes([239, 206, 244, 255]),
-            Fix::from_le_bytes([112, 103, 198, 255]),
-            Fix::from_le_bytes([61, 171, 240, 255]),
-            Fix::from_le_bytes([2, 44, 101, 0]),
-            Fix::from_le_bytes([185, 116, 198, 255]),
-            Fix::from_le_bytes([128, 61, 217, 255]),
-            Fix::from_le_bytes([216, 111, 205, 255]),
-            Fix::from_le_bytes([111, 156, 245, 255]),
-            Fix::from_le_bytes([67, 126, 51, 0]),
-            Fix::from_le_bytes([40, 72, 65, 0]),
-            Fix::from_le_bytes([55, 1, 49, 0]),
-            Fix::from_le_bytes([45, 132, 98, 0]),
-            Fix::from_le_bytes([170, 147, 246, 255]),
-            Fix::from_le_bytes([221, 24, 8, 0]),
-            Fix::from_le_bytes([13, 54, 244, 255]),
-            Fix::from_le_bytes([28, 158, 9, 0]),
-            Fix::from_le_bytes([85, 124, 253, 255]),
-            Fix::from_le_bytes([117, 188, 25, 0]),
-            Fix::from_le_bytes([242, 123, 1, 0]),
-            Fix::from_le_bytes([15, 0, 23, 0]),
-            Fix::from_le_bytes([253, 78, 254, 255]),
-            Fix::from_le_bytes([233, 250, 0, 0]),
-            Fix::from_le_bytes([218, 222, 23, 0]),
-            Fix::from_le_bytes([33, 3, 38, 0]),
-            Fix::from_le_bytes([0, 41, 24, 0]),
-            Fix::from_le_bytes([126, 231, 50, 0]),
-            Fix::from_le_bytes([19, 119, 223, 255]),
-            Fix::from_le_bytes([217, 56, 8, 0]),
-            Fix::from_le_bytes([8, 58, 27, 0]),
-            Fix::from_le_bytes([24, 203, 21, 0]),
-            Fix::from_le_bytes([86, 212, 30, 0]),
-            Fix::from_le_bytes([228, 253, 4, 0]),
-            Fix::from_le_bytes([210, 167, 45, 0]),
-            Fix::from_le_bytes([101, 88, 40, 0]),
-            Fix::from_le_bytes([22, 178, 217, 255]),
-            Fix::from_le_bytes([59, 39, 225, 255]),
-            Fix::from_le_bytes([82, 87, 8, 0]),
-            Fix::from_le_bytes([197, 238, 207, 255]),
-            Fix::from_le_bytes([96, 81, 8, 0]),
-            Fix::from_le_bytes([72, 158, 239, 255]),
-            Fix::from_le_bytes([187, 135, 46, 0]),
-            Fix::from_le_bytes([13, 74, 37, 0]),
-            Fix::from_le_bytes([108, 177, 176, 255]),
-        ],
-        [
-            Fix::from_le_bytes([64, 225, 13, 0]),
-            Fix::from_le_bytes([2, 72, 29, 0]),
-            Fix::from_le_bytes([183, 159, 238, 255]),
-            Fix::from_le_bytes([198, 188, 212, 255]),
-            Fix::from_le_bytes([134, 102, 22, 0]),
-            Fix::from_le_bytes([164, 44, 28, 0]),
-            Fix::from_le_bytes([8, 173, 21, 0]),
-            Fix::from_le_bytes([146, 41, 255, 255]),
-            Fix::from_le_bytes([52, 64, 14, 0]),
-            Fix::from_le_bytes([34, 120, 252, 255]),
-            Fix::from_le_bytes([234, 245, 171, 255]),
-            Fix::from_le_bytes([251, 54, 248, 255]),
-            Fix::from_le_bytes([40, 19, 177, 255]),
-            Fix::from_le_bytes([247, 204, 24, 0]),
-            Fix::from_le_bytes([49, 214, 10, 0]),
-            Fix::from_le_bytes([194, 82, 245, 255]),
-            Fix::from_le_bytes([35, 213, 188, 255]),
-            Fix::from_le_bytes([243, 76, 193, 255]),
-            Fix::from_le_bytes([50, 146, 144, 255]),
-            Fix::from_le_bytes([88, 95, 168, 255]),
-            Fix::from_le_bytes([237, 3, 204, 255]),
-            Fix::from_le_bytes([19, 13, 27, 0]),
-            Fix::from_le_bytes([169, 66, 81, 0]),
-            Fix::from_le_bytes([62, 178, 65, 0]),
-            Fix::from_le_bytes([91, 76, 243, 255]),
-            Fix::from_le_bytes([253, 2, 25, 0]),
-            Fix::from_le_bytes([227, 110, 40, 0]),
-            Fix::from_le_bytes([235, 75, 204, 255]),
-            Fix::from_le_bytes([102, 116, 224, 255]),
-            Fix::from_le_bytes([120, 27, 226, 255]),
-            Fix::from_le_bytes([8, 92, 231, 255]),
-            Fix::from_le_bytes([124, 242, 41, 0]),
-            Fix::from_le_bytes([113, 185, 231, 255]),
-            Fix::from_le_bytes([142, 209, 240, 255]),
-            Fix::from_le_bytes([54, 43, 220, 255]),
-            Fix::from_le_bytes([223, 60, 202, 255]),
-            Fix::from_le_bytes([14, 156, 0, 0]),
-            Fix::from_le_bytes([224, 145, 203, 255]),
-            Fix::from_le_bytes([90, 61, 66, 0]),
-            Fix::from_le_bytes([149, 134, 232, 255]),
-            Fix::from_le_bytes([186, 81, 207, 255]),
-            Fix::from_le_bytes([10, 18, 232, 255]),
-            Fix::from_le_bytes([231, 59, 201, 255]),
-            Fix::from_le_bytes([117, 167, 242, 255]),
-            Fix::from_le_bytes([108, 18, 212, 255]),
-            Fix::from_le_bytes([80, 2, 209, 255]),
-            Fix::from_le_bytes([189, 12, 1, 0]),
-            Fix::from_le_bytes([73, 230, 244, 255]),
-            Fix::from_le_bytes([95, 143, 17, 0]),
-            Fix::from_le_bytes([217, 112, 61, 0]),
-        ],
-        [
-            Fix::from_le_bytes([137, 214, 255, 255]),
-            Fix::from_le_bytes([104, 203, 51, 0]),
-            Fix::from_le_bytes([242, 76, 11, 0]),
-            Fix::from_le_bytes([71, 183, 12, 0]),
-            Fix::from_le_bytes([10, 77, 220, 255]),
-            Fix::from_le_bytes([70, 203, 245, 255]),
-            Fix::from_le_bytes([255, 255, 31, 0]),
-            Fix::from_le_bytes([80, 21, 18, 0]),
-            Fix::from_le_bytes([28, 74, 38, 0]),
-            Fix::from_le_bytes([196, 238, 232, 255]),
-            Fix::from_le_bytes([58, 250, 12, 0]),
-            Fix::from_le_bytes([68, 55, 11, 0]),
-            Fix::from_le_bytes([160, 100, 5, 0]),
-            Fix::from_le_bytes([42, 114, 254, 255]),
-            Fix::from_le_bytes([190, 166, 37, 0]),
-            Fix::from_le_bytes([226, 77, 254, 255]),
-            Fix::from_le_bytes([43, 98, 226, 255]),
-            Fix::from_le_bytes([45, 233, 26, 0]),
-            Fix::from_le_bytes([255, 183, 22, 0]),
-            Fix::from_le_bytes([148, 170, 19, 0]),
-            Fix::from_le_bytes([167, 88, 5, 0]),
-            Fix::from_le_bytes([124, 151, 12, 0]),
-            Fix::from_le_bytes([224, 207, 226, 255]),
-            Fix::from_le_bytes([242, 8, 239, 255]),
-            Fix::from_le_bytes([66, 217, 6, 0]),
-            Fix::from_le_bytes([125, 68, 248, 255]),
-            Fix::from_le_bytes([144, 209, 7, 0]),
-            Fix::from_le_bytes([134, 118, 8, 0]),
-            Fix::from_le_bytes([144, 193, 242, 255]),
-            Fix::from_le_bytes([94, 90, 254, 255]),
-            Fix::from_le_bytes([47, 207, 46, 0]),
-            Fix::from_le_bytes([209, 149, 22, 0]),
-            Fix::from_le_bytes([239, 85, 26, 0]),
-            Fix::from_le_bytes([174, 14, 208, 255]),
-            Fix::from_le_bytes([14, 247, 244, 255]),
-            Fix::from_le_bytes([68, 162, 218, 255]),
-            Fix::from_le_bytes([57, 107, 241, 255]),
-            Fix::from_le_bytes([5, 1, 20, 0]),
-            Fix::from_le_bytes([69, 70, 18, 0]),
-            Fix::from_le_bytes([96, 60, 16, 0]),
-            Fix::from_le_bytes([80, 214, 15, 0]),
-            Fix::from_le_bytes([108, 34, 237, 255]),
-            Fix::from_le_bytes([135, 189, 220, 255]),
-            Fix::from_le_bytes([216, 230, 18, 0]),
-            Fix::from_le_bytes([254, 230, 17, 0]),
-            Fix::from_le_bytes([192, 101, 253, 255]),
-            Fix::from_le_bytes([138, 52, 5, 0]),
-            Fix::from_le_bytes([40, 80, 180, 255]),
-            Fix::from_le_bytes([106, 166, 7, 0]),
-            Fix::from_le_bytes([107, 102, 12, 0]),
-        ],
-        [
-            Fix::from_le_bytes([93, 159, 216, 255]),
-            Fix::from_le_bytes([213, 30, 238, 255]),
-            Fix::from_le_bytes([227, 179, 202, 255]),
-            Fix::from_le_bytes([40, 66, 222, 255]),
-            Fix::from_le_bytes([184, 234, 197, 255]),
-            Fix::from_le_bytes([173, 57, 173, 255]),
-            Fix::from_le_bytes([16, 173, 196, 255]),
-            Fix::from_le_bytes([15, 15, 196, 255]),
-            Fix::from_le_bytes([69, 51, 209, 255]),
-            Fix::from_le_bytes([74, 64, 175, 255]),
-            Fix::from_le_bytes([121, 185, 221, 255]),
-            Fix::from_le_bytes([137, 43, 174, 255]),
-            Fix::from_le_bytes([202, 42, 193, 255]),
-            Fix::from_le_bytes([44, 31, 223, 255]),
-            Fix::from_le_bytes([28, 215, 38, 0]),
-            Fix::from_le_bytes([245, 42, 19, 0]),
-            Fix::from_le_bytes([78, 83, 4, 0]),
-            Fix::from_le_bytes([50, 117, 208, 255]),
-            Fix::from_le_bytes([80, 231, 254, 255]),
-            Fix::from_le_bytes([216, 120, 69, 0]),
-            Fix::from_le_bytes([181, 5, 36, 0]),
-            Fix::from_le_bytes([221, 90, 2, 0]),
-            Fix::from_le_bytes([155, 97, 236, 255]),
-            Fix::from_le_bytes([250, 150, 21, 0]),
-            Fix::from_le_bytes([3, 50, 23, 0]),
-            Fix::from_le_bytes([11, 174, 184, 255]),
-            Fix::from_le_bytes([107, 87, 2, 0]),
-            Fix::from_le_bytes([252, 247, 31, 0]),
-            Fix::from_le_bytes([236, 28, 12, 0]),
-            Fix::from_le_bytes([224, 240, 51, 0]),
-            Fix::from_le_bytes([114, 91, 33, 0]),
-            Fix::from_le_bytes([87, 217, 247, 255]),
-            Fix::from_le_bytes([135, 118, 20, 0]),
-            Fix::from_le_bytes([170, 219, 19, 0]),
-            Fix::from_le_bytes([0, 126, 67, 0]),
-            Fix::from_le_bytes([31, 232, 2, 0]),
-            Fix::from_le_bytes([35, 99, 11, 0]),
-            Fix::from_le_bytes([71, 155, 44, 0]),
-            Fix::from_le_bytes([78, 130, 195, 255]),
-            Fix::from_le_bytes([137, 2, 19, 0]),
-            Fix::from_le_bytes([47, 179, 26, 0]),
-            Fix::from_le_bytes([4, 72, 7, 0]),
-            Fix::from_le_bytes([112, 239, 0, 0]),
-            Fix::from_le_bytes([52, 253, 233, 255]),
-            Fix::from_le_bytes([75, 255, 254, 255]),
-            Fix::from_le_bytes([173, 128, 212, 255]),
-            Fix::from_le_bytes([248, 235, 6, 0]),
-            Fix::from_le_bytes([63, 0, 242, 255]),
-            Fix::from_le_bytes([98, 4, 246, 255]),
-            Fix::from_le_bytes([176, 105, 35, 0]),
-        ],
-        [
-            Fix::from_le_bytes([9, 102, 13, 0]),
-            Fix::from_le_bytes([223, 109, 249, 255]),
-            Fix::from_le_bytes([154, 243, 1, 0]),
-            Fix::from_le_bytes([214, 214, 30, 0]),
-            Fix::from_le_bytes([246, 252, 10, 0]),
-            Fix::from_le_bytes([77, 48, 2, 0]),
-            Fix::from_le_bytes([106, 145, 244, 255]),
-            Fix::from_le_bytes([23, 185, 249, 255]),
-            Fix::from_le_bytes([153, 60, 253, 255]),
-            Fix::from_le_bytes([2, 183, 246, 255]),
-            Fix::from_le_bytes([85, 92, 139, 0]),
-            Fix::from_le_bytes([113, 16, 9, 0]),
-            Fix::from_le_bytes([66, 15, 232, 255]),
-            Fix::from_le_bytes([121, 211, 255, 255]),
-            Fix::from_le_bytes([45, 47, 20, 0]),
-            Fix::from_le_bytes([148, 110, 213, 255]),
-            Fix::from_le_bytes([179, 151, 81, 0]),
-            Fix::from_le_bytes([53, 19, 162, 0]),
-            Fix::from_le_bytes([118, 103, 80, 0]),
-            Fix::from_le_bytes([146, 235, 18, 0]),
-            Fix::from_le_bytes([205, 161, 44, 0]),
-            Fix::from_le_bytes([12, 232, 69, 0]),
-            Fix::from_le_bytes([91, 124, 26, 0]),
-            Fix::from_le_bytes([74, 143, 79, 0]),
-            Fix::from_le_bytes([105, 49, 6, 0]),
-            Fix::from_le_bytes([122, 129, 213, 255]),
-            Fix::from_le_bytes([58, 85, 43, 0]),
-            Fix::from_le_bytes([36, 109, 224, 255]),
-            Fix::from_le_bytes([220, 249, 26, 0]),
-            Fix::from_le_bytes([134, 38, 208, 255]),
-            Fix::from_le_bytes([117, 246, 229, 255]),
-            Fix::from_le_bytes([249, 185, 20, 0]),
-            Fix::from_le_bytes([66, 157, 249, 255]),
-            Fix::from_le_bytes([88, 106, 10, 0]),
-            Fix::from_le_bytes([181, 137, 37, 0]),
-            Fix::from_le_bytes([214, 167, 249, 255]),
-            Fix::from_le_bytes([253, 88, 199, 255]),
-            Fix::from_le_bytes([146, 188, 237, 255]),
-            Fix::from_le_bytes([56, 157, 238, 255]),
-            Fix::from_le_bytes([122, 65, 222, 255]),
-            Fix::from_le_bytes([204, 44, 237, 255]),
-            Fix::from_le_bytes([22, 11, 14, 0]),
-            Fix::from_le_bytes([19, 4, 239, 255]),
-            Fix::from_le_bytes([4, 153, 24, 0]),
-            Fix::from_le_bytes([118, 246, 239, 255]),
-            Fix::from_le_bytes([89, 139, 12, 0]),
-            Fix::from_le_bytes([143, 138, 242, 255]),
-            Fix::from_le_bytes([188, 157, 227, 255]),
-            Fix::from_le_bytes([112, 8, 246, 255]),
-            Fix::from_le_bytes([192, 71, 212, 255]),
-        ],
-        [
-            Fix::from_le_bytes([179, 4, 1, 0]),
-            Fix::from_le_bytes([61, 67, 9, 0]),
-            Fix::from_le_bytes([246, 52, 10, 0]),
-            Fix::from_le_bytes([180, 167, 85, 0]),
-            Fix::from_le_bytes([144, 0, 36, 0]),
-            Fix::from_le_bytes([149, 110, 8, 0]),
-            Fix::from_le_bytes([100, 72, 25, 0]),
-            Fix::from_le_bytes([209, 176, 32, 0]),
-            Fix::from_le_bytes([201, 215, 4, 0]),
-            Fix::from_le_bytes([180, 238, 232, 255]),
-            Fix::from_le_bytes([208, 132, 97, 0]),
-            Fix::from_le_bytes([228, 118, 239, 255]),
-            Fix::from_le_bytes([167, 50, 220, 255]),
-            Fix::from_le_bytes([187, 65, 2, 0]),
-            Fix::from_le_bytes([110, 116, 67, 0]),
-            Fix::from_le_bytes([172, 152, 115, 0]),
-            Fix::from_le_bytes([58, 230, 73, 0]),
-            Fix::from_le_bytes([189, 224, 17, 0]),
-            Fix::from_le_bytes([232, 59, 59, 0]),
-            Fix::from_le_bytes([93, 253, 87, 0]),
-            Fix::from_le_bytes([144, 169, 33, 0]),
-            Fix::from_le_bytes([167, 11, 244, 255]),
-            Fix::from_le_bytes([144, 210, 68, 0]),
-            Fix::from_le_bytes([123, 161, 78, 0]),
-            Fix::from_le_bytes([164, 101, 20, 0]),
-            Fix::from_le_bytes([22, 4, 140, 0]),
-            Fix::from_le_bytes([12, 160, 73, 0]),
-            Fix::from_le_bytes([78, 60, 26, 0]),
-            Fix::from_le_bytes([82, 243, 40, 0]),
-            Fix::from_le_bytes([227, 188, 206, 255]),
-            Fix::from_le_bytes([2, 239, 246, 255]),
-            Fix::from_le_bytes([158, 6, 240, 255]),
-            Fix::from_le_bytes([29, 233, 40, 0]),
-            Fix::from_le_bytes([205, 66, 247, 255]),
-            Fix::from_le_bytes([120, 154, 3, 0]),
-            Fix::from_le_bytes([10, 61, 248, 255]),
-            Fix::from_le_bytes([45, 15, 29, 0]),
-            Fix::from_le_bytes([21, 48, 17, 0]),
-            Fix::from_le_bytes([35, 27, 253, 255]),
-            Fix::from_le_bytes([222, 203, 65, 0]),
-            Fix::from_le_bytes([186, 189, 219, 255]),
-            Fix::from_le_bytes([116, 32, 9, 0]),
-            Fix::from_le_bytes([238, 255, 21, 0]),
-            Fix::from_le_bytes([12, 133, 171, 255]),
-            Fix::from_le_bytes([144, 230, 10, 0]),
-            Fix::from_le_bytes([70, 9, 189, 255]),
-            Fix::from_le_bytes([229, 51, 22, 0]),
-            Fix::from_le_bytes([222, 225, 186, 255]),
-            Fix::from_le_bytes([21, 95, 52, 0]),
-            Fix::from_le_bytes([82, 19, 26, 0]),
-        ],
-        [
-            Fix::from_le_bytes([119, 69, 16, 0]),
-            Fix::from_le_bytes([74, 90, 251, 255]),
-            Fix::from_le_bytes([121, 190, 17, 0]),
-            Fix::from_le_bytes([232, 135, 165, 255]),
-            Fix::from_le_bytes([216, 232, 10, 0]),
-            Fix::from_le_bytes([139, 245, 30, 0]),
-            Fix::from_le_bytes([49, 98, 16, 0]),
-            Fix::from_le_bytes([124, 153, 21, 0]),
-            Fix::from_le_bytes([63, 184, 10, 0]),
-            Fix::from_le_bytes([15, 109, 39, 0]),
-            Fix::from_le_bytes([40, 40, 163, 255]),
-            Fix::from_le_bytes([205, 29, 5, 0]),
-            Fix::from_le_bytes([4, 140, 254, 255]),
-            Fix::from_le_bytes([45, 109, 247, 255]),
-            Fix::from_le_bytes([89, 222, 26, 0]),
-            Fix::from_le_bytes([37, 64, 202, 255]),
-            Fix::from_le_bytes([140, 109, 238, 255]),
-            Fix::from_le_bytes([117, 51, 220, 255]),
-            Fix::from_le_bytes([20, 59, 242, 255]),
-            Fix::from_le_bytes([73, 41, 254, 255]),
-            Fix::from_le_bytes([103, 85, 4, 0]),
-            Fix::from_le_bytes([98, 62, 218, 255]),
-            Fix::from_le_bytes([51, 95, 224, 255]),
-            Fix::from_le_bytes([170, 133, 163, 255]),
-            Fix::from_le_bytes([167, 236, 219, 255]),
-            Fix::from_le_bytes([251, 74, 166, 255]),
-            Fix::from_le_bytes([230, 85, 179, 255]),
-            Fix::from_le_bytes([119, 141, 2, 0]),
-            Fix::from_le_bytes([103, 22, 9, 0]),
-            Fix::from_le_bytes([0, 232, 78, 0]),
-            Fix::from_le_bytes([104, 25, 93, 0]),
-            Fix::from_le_bytes([168, 181, 5, 0]),
-            Fix::from_le_bytes([212, 58, 83, 0]),
-            Fix::from_le_bytes([98, 199, 118, 0]),
-            Fix::from_le_bytes([64, 10, 21, 0]),
-            Fix::from_le_bytes([181, 43, 15, 0]),
-            Fix::from_le_bytes([211, 249, 254, 255]),
-            Fix::from_le_bytes([120, 47, 70, 0]),
-            Fix::from_le_bytes([224, 23, 254, 255]),
-            Fix::from_le_bytes([238, 19, 250, 255]),
-            Fix::from_le_bytes([162, 170, 43, 0]),
-            Fix::from_le_bytes([252, 79, 65, 0]),
-            Fix::from_le_bytes([250, 69, 61, 0]),
-            Fix::from_le_bytes([220, 210, 10, 0]),
-            Fix::from_le_bytes([160, 176, 73, 0]),
-            Fix::from_le_bytes([90, 248, 87, 0]),
-            Fix::from_le_bytes([136, 18, 32, 0]),
-            Fix::from_le_bytes([102, 111, 20, 0]),
-            Fix::from_le_bytes([121, 137, 26, 0]),
-            Fix::from_le_bytes([246, 190, 44, 0]),
-        ],
-        [
-            Fix::from_le_bytes([158, 100, 229, 255]),
-            Fix::from_le_bytes([152, 175, 37, 0]),
-            Fix::from_le_bytes([137, 8, 23, 0]),
-            Fix::from_le_bytes([188, 61, 79, 0]),
-            Fix::from_le_bytes([29, 177, 249, 255]),
-            Fix::from_le_bytes([236, 18, 245, 255]),
-            Fix::from_le_bytes([2, 55, 51, 0]),
-            Fix::from_le_bytes([226, 155, 11, 0]),
-            Fix::from_le_bytes([220, 49, 193, 255]),
-            Fix::from_le_bytes([44, 72, 24, 0]),
-            Fix::from_le_bytes([249, 221, 122, 0]),
-            Fix::from_le_bytes([255, 92, 0, 0]),
-            Fix::from_le_bytes([63, 185, 15, 0]),
-            Fix::from_le_bytes([119, 92, 2, 0]),
-            Fix::from_le_bytes([107, 195, 27, 0]),
-            Fix::from_le_bytes([168, 44, 47, 0]),
-            Fix::from_le_bytes([141, 144, 148, 0]),
-            Fix::from_le_bytes([97, 203, 79, 0]),
-            Fix::from_le_bytes([58, 119, 76, 0]),
-            Fix::from_le_bytes([159, 87, 233, 255]),
-            Fix::from_le_bytes([39, 126, 240, 255]),
-            Fix::from_le_bytes([119, 116, 55, 0]),
-            Fix::from_le_bytes([38, 44, 212, 255]),
-            Fix::from_le_bytes([27, 29, 51, 0]),
-            Fix::from_le_bytes([183, 43, 66, 0]),
-            Fix::from_le_bytes([179, 24, 228, 255]),
-            Fix::from_le_bytes([182, 163, 237, 255]),
-            Fix::from_le_bytes([178, 156, 4, 0]),
-            Fix::from_le_bytes([147, 192, 241, 255]),
-            Fix::from_le_bytes([134, 209, 218, 255]),
-            Fix::from_le_bytes([27, 13, 225, 255]),
-            Fix::from_le_bytes([58, 128, 17, 0]),
-            Fix::from_le_bytes([199, 23, 232, 255]),
-            Fix::from_le_bytes([9, 97, 2, 0]),
-            Fix::from_le_bytes([9, 205, 19, 0]),
-            Fix::from_le_bytes([92, 85, 40, 0]),
-            Fix::from_le_bytes([118, 79, 238, 255]),
-            Fix::from_le_bytes([152, 192, 254, 255]),
-            Fix::from_le_bytes([174, 138, 249, 255]),
-            Fix::from_le_bytes([89, 47, 17, 0]),
-            Fix::from_le_bytes([250, 4, 217, 255]),
-            Fix::from_le_bytes([149, 133, 48, 0]),
-            Fix::from_le_bytes([94, 189, 235, 255]),
-            Fix::from_le_bytes([212, 234, 238, 255]),
-            Fix::from_le_bytes([106, 11, 32, 0]),
-            Fix::from_le_bytes([195, 40, 236, 255]),
-            Fix::from_le_bytes([181, 82, 8, 0]),
-            Fix::from_le_bytes([175, 162, 248, 255]),
-            Fix::from_le_bytes([94, 157, 238, 255]),
-            Fix::from_le_bytes([20, 199, 185, 255]),
-        ],
-        [
-            Fix::from_le_bytes([119, 237, 23, 0]),
-            Fix::from_le_bytes([253, 121, 3, 0]),
-            Fix::from_le_bytes([254, 247, 248, 255]),
-            Fix::from_le_bytes([112, 89, 107, 0]),
-            Fix::from_le_bytes([54, 246, 254, 255]),
-            Fix::from_le_bytes([249, 137, 253, 255]),
-            Fix::from_le_bytes([43, 24, 250, 255]),
-            Fix::from_le_bytes([90, 222, 3, 0]),
-            Fix::from_le_bytes([127, 33, 0, 0]),
-            Fix::from_le_bytes([231, 137, 242, 255]),
-            Fix::from_le_bytes([110, 210, 66, 0]),
-            Fix::from_le_bytes([70, 53, 189, 255]),
-            Fix::from_le_bytes([116, 90, 232, 255]),
-            Fix::from_le_bytes([209, 226, 247, 255]),
-            Fix::from_le_bytes([173, 112, 235, 255]),
-            Fix::from_le_bytes([241, 165, 245, 255]),
-            Fix::from_le_bytes([46, 33, 36, 0]),
-            Fix::from_le_bytes([228, 24, 250, 255]),
-            Fix::from_le_bytes([23, 7, 44, 0]),
-            Fix::from_le_bytes([205, 186, 18, 0]),
-            Fix::from_le_bytes([91, 18, 184, 255]),
-            Fix::from_le_bytes([127, 3, 55, 0]),
-            Fix::from_le_bytes([63, 150, 249, 255]),
-            Fix::from_le_bytes([67, 135, 238, 255]),
-            Fix::from_le_bytes([146, 139, 34, 0]),
-            Fix::from_le_bytes([72, 10, 226, 255]),
-            Fix::from_le_bytes([182, 168, 234, 255]),
-            Fix::from_le_bytes([52, 136, 243, 255]),
-            Fix::from_le_bytes([67, 63, 222, 255]),
-            Fix::from_le_bytes([170, 150, 44, 0]),
-            Fix::from_le_bytes([104, 61, 250, 255]),
-            Fix::from_le_bytes([78, 22, 0, 0]),
-            Fix::from_le_bytes([35, 244, 238, 255]),
-            Fix::from_le_bytes([101, 169, 67, 0]),
-            Fix::from_le_bytes([238, 103, 240, 255]),
-            Fix::from_le_bytes([118, 104, 17, 0]),
-            Fix::from_le_bytes([0, 72, 10, 0]),
-            Fix::from_le_bytes([87, 162, 25, 0]),
-            Fix::from_le_bytes([155, 89, 252, 255]),
-            Fix::from_le_bytes([65, 151, 6, 0]),
-            Fix::from_le_bytes([38, 162, 51, 0]),
-            Fix::from_le_bytes([94, 67, 11, 0]),
-            Fix::from_le_bytes([79, 99, 230, 255]),
-            Fix::from_le_bytes([23, 168, 15, 0]),
-            Fix::from_le_bytes([108, 182, 20, 0]),
-            Fix::from_le_bytes([76, 112, 60, 0]),
-            Fix::from_le_bytes([55, 234, 232, 255]),
-            Fix::from_le_bytes([147, 221, 0, 0]),
-            Fix::from_le_bytes([243, 130, 189, 255]),
-            Fix::from_le_bytes([244, 55, 193, 255]),
-        ],
-        [
-            Fix::from_le_bytes([190, 242, 201, 255]),
-            Fix::from_le_bytes([83, 79, 223, 255]),
-            Fix::from_le_bytes([177, 96, 234, 255]),
-            Fix::from_le_bytes([182, 114, 29, 0]),
-            Fix::from_le_bytes([11, 26, 18, 0]),
-            Fix::from_le_bytes([13, 17, 27, 0]),
-            Fix::from_le_bytes([46, 59, 40, 0]),
-            Fix::from_le_bytes([101, 73, 8, 0]),
-            Fix::from_le_bytes([198, 61, 64, 0]),
-            Fix::from_le_bytes([168, 106, 65, 0]),
-            Fix::from_le_bytes([180, 30, 65, 0]),
-            Fix::from_le_bytes([154, 10, 222, 255]),
-            Fix::from_le_bytes([166, 104, 229, 255]),
-            Fix::from_le_bytes([247, 171, 195, 255]),
-            Fix::from_le_bytes([160, 45, 234, 255]),
-            Fix::from_le_bytes([180, 139, 240, 255]),
-            Fix::from_le_bytes([219, 56, 211, 255]),
-            Fix::from_le_bytes([19, 3, 244, 255]),
-            Fix::from_le_bytes([168, 225, 66, 0]),
-            Fix::from_le_bytes([182, 86, 245, 255]),
-            Fix::from_le_bytes([222, 70, 245, 255]),
-            Fix::from_le_bytes([14, 169, 241, 255]),
-            Fix::from_le_bytes([158, 6, 3, 0]),
-            Fix::from_le_bytes([177, 33, 241, 255]),
-            Fix::from_le_bytes([50, 224, 31, 0]),
-            Fix::from_le_bytes([235, 203, 226, 255]),
-            Fix::from_le_bytes([163, 164, 253, 255]),
-            Fix::from_le_bytes([40, 237, 223, 255]),
-            Fix::from_le_bytes([48, 161, 240, 255]),
-            Fix::from_le_bytes([253, 79, 206, 255]),
-            Fix::from_le_bytes([190, 175, 211, 255]),
-            Fix::from_le_bytes([0, 199, 11, 0]),
-            Fix::from_le_bytes([182, 144, 234, 255]),
-            Fix::from_le_bytes([252, 49, 250, 255]),
-            Fix::from_le_bytes([108, 134, 255, 255]),
-            Fix::from_le_bytes([188, 56, 252, 255]),
-            Fix::from_le_bytes([105, 246, 246, 255]),
-            Fix::from_le_bytes([108, 140, 222, 255]),
-            Fix::from_le_bytes([66, 189, 15, 0]),
-            Fix::from_le_bytes([229, 13, 247, 255]),
-            Fix::from_le_bytes([170, 133, 17, 0]),
-            Fix::from_le_bytes([240, 123, 251, 255]),
-            Fix::from_le_bytes([83, 79, 52, 0]),
-            Fix::from_le_bytes([131, 65, 23, 0]),
-            Fix::from_le_bytes([234, 145, 40, 0]),
-            Fix::from_le_bytes([149, 155, 4, 0]),
-            Fix::from_le_bytes([160, 59, 52, 0]),
-            Fix::from_le_bytes([48, 152, 17, 0]),
-            Fix::from_le_bytes([204, 230, 27, 0]),
-            Fix::from_le_bytes([175, 3, 196, 255]),
-        ],
-        [
-            Fix::from_le_bytes([92, 223, 214, 255]),
-            Fix::from_le_bytes([134, 43, 31, 0]),
-            Fix::from_le_bytes([141, 249, 9, 0]),
-            Fix::from_le_bytes([157, 146, 240, 255]),
-            Fix::from_le_bytes([58, 171, 44, 0]),
-            Fix::from_le_bytes([24, 189, 234, 255]),
-            Fix::from_le_bytes([239, 129, 26, 0]),
-            Fix::from_le_bytes([232, 228, 243, 255]),
-            Fix::from_le_bytes([125, 146, 39, 0]),
-            Fix::from_le_bytes([79, 240, 245, 255]),
-            Fix::from_le_bytes([183, 17, 49, 0]),
-            Fix::from_le_bytes([105, 238, 237, 255]),
-            Fix::from_le_bytes([77, 180, 44, 0]),
-            Fix::from_le_bytes([88, 33, 18, 0]),
-            Fix::from_le_bytes([144, 148, 222, 255]),
-            Fix::from_le_bytes([135, 170, 212, 255]),
-            Fix::from_le_bytes([174, 127, 16, 0]),
-            Fix::from_le_bytes([126, 238, 29, 0]),
-            Fix::from_le_bytes([136, 30, 241, 255]),
-            Fix::from_le_bytes([71, 217, 239, 255]),
-            Fix::from_le_bytes([248, 249, 241, 255]),
-            Fix::from_le_bytes([248, 123, 1, 0]),
-            Fix::from_le_bytes([238, 172, 242, 255]),
-            Fix::from_le_bytes([144, 92, 218, 255]),
-            Fix::from_le_bytes([129, 61, 16, 0]),
-            Fix::from_le_bytes([44, 45, 234, 255]),
-            Fix::from_le_bytes([142, 222, 21, 0]),
-            Fix::from_le_bytes([157, 241, 255, 255]),
-            Fix::from_le_bytes([160, 123, 234, 255]),
-            Fix::from_le_bytes([128, 140, 223, 255]),
-            Fix::from_le_bytes([192, 241, 14, 0]),
-            Fix::from_le_bytes([45, 8, 17, 0]),
-            Fix::from_le_bytes([150, 165, 3, 0]),
-            Fix::from_le_bytes([167, 92, 4, 0]),
-            Fix::from_le_bytes([42, 118, 224, 255]),
-            Fix::from_le_bytes([194, 159, 10, 0]),
-            Fix::from_le_bytes([79, 244, 0, 0]),
-            Fix::from_le_bytes([143, 126, 33, 0]),
-            Fix::from_le_bytes([91, 73, 233, 255]),
-            Fix::from_le_bytes([72, 27, 243, 255]),
-            Fix::from_le_bytes([147, 190, 209, 255]),
-            Fix::from_le_bytes([249, 220, 249, 255]),
-            Fix::from_le_bytes([38, 157, 3, 0]),
-            Fix::from_le_bytes([200, 218, 255, 255]),
-            Fix::from_le_bytes([172, 196, 254, 255]),
-            Fix::from_le_bytes([102, 93, 49, 0]),
-            Fix::from_le_bytes([66, 228, 39, 0]),
-            Fix::from_le_bytes([128, 87, 43, 0]),
-            Fix::from_le_bytes([125, 253, 4, 0]),
-            Fix::from_le_bytes([82, 145, 205, 255]),
-        ],
-        [
-            Fix::from_le_bytes([93, 187, 37, 0]),
-            Fix::from_le_bytes([115, 76, 10, 0]),
-            Fix::from_le_bytes([116, 22, 31, 0]),
-            Fix::from_le_bytes([159, 11, 2, 0]),
-            Fix::from_le_bytes([167, 48, 27, 0]),
-            Fix::from_le_bytes([252, 161, 21, 0]),
-            Fix::from_le_bytes([188, 175, 2, 0]),
-            Fix::from_le_bytes([234, 255, 35, 0]),
-            Fix::from_le_bytes([6, 38, 22, 0]),
-            Fix::from_le_bytes([28, 170, 1, 0]),
-            Fix::from_le_bytes([169, 20, 63, 0]),
-            Fix::from_le_bytes([176, 171, 16, 0]),
-            Fix::from_le_bytes([29, 154, 237, 255]),
-            Fix::from_le_bytes([94, 227, 22, 0]),
-            Fix::from_le_bytes([61, 240, 221, 255]),
-            Fix::from_le_bytes([254, 126, 71, 0]),
-            Fix::from_le_bytes([191, 79, 229, 255]),
-            Fix::from_le_bytes([84, 160, 223, 255]),
-            Fix::from_le_bytes([164, 59, 234, 255]),
-            Fix::from_le_bytes([76, 151, 33, 0]),
-            Fix::from_le_bytes([69, 14, 249, 255]),
-            Fix::from_le_bytes([202, 110, 55, 0]),
-            Fix::from_le_bytes([21, 104, 31, 0]),
-            Fix::from_le_bytes([32, 255, 136, 0]),
-            Fix::from_le_bytes([16, 241, 210, 255]),
-            Fix::from_le_bytes([223, 97, 99, 0]),
-            Fix::from_le_bytes([98, 27, 65, 0]),
-            Fix::from_le_bytes([2, 124, 255, 255]),
-            Fix::from_le_bytes([42, 100, 221, 255]),
-            Fix::from_le_bytes([34, 155, 154, 255]),
-            Fix::from_le_bytes([121, 233, 14, 0]),
-            Fix::from_le_bytes([192, 161, 26, 0]),
-            Fix::from_le_bytes([76, 223, 220, 255]),
-            Fix::from_le_bytes([64, 144, 229, 255]),
-            Fix::from_le_bytes([113, 172, 30, 0]),
-            Fix::from_le_bytes([34, 35, 217, 255]),
-            Fix::from_le_bytes([145, 195, 240, 255]),
-            Fix::from_le_bytes([143, 254, 243, 255]),
-            Fix::from_le_bytes([48, 180, 37, 0]),
-            Fix::from_le_bytes([211, 79, 35, 0]),
-            Fix::from_le_bytes([131, 215, 255, 255]),
-            Fix::from_le_bytes([187, 163, 232, 255]),
-            Fix::from_le_bytes([76, 165, 254, 255]),
-            Fix::from_le_bytes([49, 12, 42, 0]),
-            Fix::from_le_bytes([48, 106, 222, 255]),
-            Fix::from_le_bytes([246, 247, 160, 255]),
-            Fix::from_le_bytes([217, 78, 247, 255]),
-            Fix::from_le_bytes([147, 246, 0, 0]),
-            Fix::from_le_bytes([222, 136, 0, 0]),
-            Fix::from_le_bytes([224, 228, 110, 0]),
-        ],
-    ],
-    biases: [
-        Fix::from_le_bytes([237, 224, 38, 0]),
-        Fix::from_le_bytes([248, 202, 1, 0]),
-        Fix::from_le_bytes([116, 197, 74, 0]),
-        Fix::from_le_bytes([10, 164, 79, 0]),
-        Fix::from_le_bytes([16, 93, 6, 0]),
-        Fix::from_le_bytes([127, 166, 59, 0]),
-        Fix::from_le_bytes([46, 175, 64, 0]),
-        Fix::from_le_bytes([106, 217, 17, 0]),
-        Fix::from_le_bytes([168, 20, 81, 0]),
-        Fix::from_le_bytes([178, 39, 45, 0]),
-        Fix::from_le_bytes([143, 165, 213, 255]),
-        Fix::from_le_bytes([181, 125, 71, 0]),
-        Fix::from_le_bytes([7, 138, 248, 255]),
-        Fix::from_le_bytes([253, 248, 251, 255]),
-        Fix::from_le_bytes([163, 4, 99, 0]),
-        Fix::from_le_bytes([87, 241, 20, 0]),
-        Fix::from_le_bytes([3, 132, 6, 0]),
-        Fix::from_le_bytes([5, 55, 14, 0]),
-        Fix::from_le_bytes([64, 119, 11, 0]),
-        Fix::from_le_bytes([206, 232, 14, 0]),
-    ],
-},
-	layer1: KartoffelLayer {
-    weights: [
-        [
-            Fix::from_le_bytes([123, 188, 43, 0]),
-            Fix::from_le_bytes([39, 175, 11, 0]),
-            Fix::from_le_bytes([114, 126, 214, 255]),
-            Fix::from_le_bytes([48, 235, 41, 0]),
-            Fix::from_le_bytes([182, 56, 192, 255]),
-            Fix::from_le_bytes([198, 187, 184, 255]),
-            Fix::from_le_bytes([108, 15, 97, 0]),
-            Fix::from_le_bytes([179, 108, 178, 255]),
-            Fix::from_le_bytes([59, 63, 224, 255]),
-            Fix::from_le_bytes([130, 210, 24, 0]),
-            Fix::from_le_bytes([176, 85, 12, 0]),
-            Fix::from_le_bytes([173, 179, 215, 255]),
-            Fix::from_le_bytes([208, 102, 10, 0]),
-            Fix::from_le_bytes([71, 148, 14, 0]),
-            Fix::from_le_bytes([250, 10, 15, 0]),
-            Fix::from_le_bytes([66, 87, 67, 0]),
-            Fix::from_le_bytes([195, 79, 228, 255]),
-            Fix::from_le_bytes([43, 197, 42, 0]),
-            Fix::from_le_bytes([240, 1, 239, 255]),
-            Fix::from_le_bytes([172, 40, 27, 0]),
-        ],
-        [
-            Fix::from_le_bytes([136, 22, 27, 0]),
-            Fix::from_le_bytes([6, 77, 35, 0]),
-            Fix::from_le_bytes([223, 153, 231, 255]),
-            Fix::from_le_bytes([209, 10, 62, 0]),
-            Fix::from_le_bytes([52, 60, 20, 0]),
-            Fix::from_le_bytes([198, 68, 214, 255]),
-            Fix::from_le_bytes([86, 206, 166, 255]),
-            Fix::from_le_bytes([151, 219, 28, 0]),
-            Fix::from_le_bytes([191, 85, 240, 255]),
-            Fix::from_le_bytes([155, 242, 172, 255]),
-            Fix::from_le_bytes([150, 236, 182, 255]),
-            Fix::from_le_bytes([100, 28, 108, 0]),
-            Fix::from_le_bytes([104, 174, 174, 255]),
-            Fix::from_le_bytes([140, 19, 23, 0]),
-            Fix::from_le_bytes([59, 105, 148, 0]),
-            Fix::from_le_bytes([40, 181, 131, 255]),
-            Fix::from_le_bytes([120, 84, 239, 255]),
-            Fix::from_le_bytes([189, 19, 172, 255]),
-            Fix::from_le_bytes([121, 154, 232, 255]),
-            Fix::from_le_bytes([165, 7, 247, 255]),
-        ],
-        [
-            Fix::from_le_bytes([220, 158, 98, 0]),
-            Fix::from_le_bytes([72, 109, 5, 0]),
-            Fix::from_le_bytes([176, 149, 103, 0]),
-            Fix::from_le_bytes([232, 118, 192, 255]),
-            Fix::from_le_bytes([81, 52, 42, 0]),
-            Fix::from_le_bytes([41, 51, 101, 0]),
-            Fix::from_le_bytes([222, 248, 12, 0]),
-            Fix::from_le_bytes([210, 119, 165, 255]),
-            Fix::from_le_bytes([180, 200, 35, 0]),
-            Fix::from_le_bytes([230, 212, 173, 255]),
-            Fix::from_le_bytes([135, 220, 33, 0]),
-            Fix::from_le_bytes([137, 203, 190, 255]),
-            Fix::from_le_bytes([120, 41, 7, 0]),
-            Fix::from_le_bytes([62, 40, 173, 255]),
-            Fix::from_le_bytes([234, 140, 79, 0]),
-            Fix::from_le_bytes([218, 4, 80, 0]),
-            Fix::from_le_bytes([83, 28, 115, 0]),
-            Fix::from_le_bytes([200, 98, 77, 0]),
-            Fix::from_le_bytes([187, 94, 20, 0]),
-            Fix::from_le_bytes([26, 212, 203, 255]),
-        ],
-        [
-            Fix::from_le_bytes([224, 56, 247, 255]),
-            Fix::from_le_bytes([210, 18, 26, 0]),
-            Fix::from_le_bytes([41, 109, 36, 0]),
-            Fix::from_le_bytes([69, 173, 226, 255]),
-            Fix::from_le_bytes([79, 127, 96, 0]),
-            Fix::from_le_bytes([193, 237, 184, 255]),
-            Fix::from_le_bytes([150, 62, 188, 255]),
-            Fix::from_le_bytes([120, 179, 84, 0]),
-            Fix::from_le_bytes([195, 157, 15, 0]),
-            Fix::from_le_bytes([55, 38, 242, 255]),
-            Fix::from_le_bytes([141, 31, 232, 255]),
-            Fix::from_le_bytes([190, 33, 250, 255]),
-            Fix::from_le_bytes([120, 22, 137, 0]),
-            Fix::from_le_bytes([156, 125, 173, 0]),
-            Fix::from_le_bytes([57, 215, 10, 0]),
-            Fix::from_le_bytes([172, 16, 79, 0]),
-            Fix::from_le_bytes([254, 112, 66, 0]),
-            Fix::from_le_bytes([34, 172, 242, 255]),
-            Fix::from_le_bytes([244, 127, 171, 255]),
-            Fix::from_le_bytes([62, 233, 68, 0]),
-        ],
-        [
-            Fix::from_le_bytes([248, 152, 204, 255]),
-            Fix::from_le_bytes([214, 1, 254, 255]),
-            Fix::from_le_bytes([180, 0, 243, 255]),
-            Fix::from_le_bytes([34, 116, 54, 0]),
-            Fix::from_le_bytes([138, 215, 235, 255]),
-            Fix::from_le_bytes([104, 70, 255, 255]),
-            Fix::from_le_bytes([48, 222, 214, 255]),
-            Fix::from_le_bytes([154, 188, 214, 255]),
-            Fix::from_le_bytes([218, 119, 35, 0]),
-            Fix::from_le_bytes([86, 198, 51, 0]),
-            Fix::from_le_bytes([139, 212, 214, 255]),
-            Fix::from_le_bytes([134, 241, 247, 255]),
-            Fix::from_le_bytes([190, 227, 176, 255]),
-            Fix::from_le_bytes([104, 25, 65, 0]),
-            Fix::from_le_bytes([7, 87, 17, 0]),
-            Fix::from_le_bytes([195, 163, 209, 255]),
-            Fix::from_le_bytes([169, 136, 79, 0]),
-            Fix::from_le_bytes([59, 156, 255, 255]),
-            Fix::from_le_bytes([105, 228, 238, 255]),
-            Fix::from_le_bytes([31, 215, 241, 255]),
-        ],
-        [
-            Fix::from_le_bytes([203, 241, 231, 255]),
-            Fix::from_le_bytes([186, 26, 45, 0]),
-            Fix::from_le_bytes([89, 158, 60, 0]),
-            Fix::from_le_bytes([214, 90, 61, 0]),
-            Fix::from_le_bytes([246, 51, 31, 0]),
-            Fix::from_le_bytes([245, 252, 32, 0]),
-            Fix::from_le_bytes([111, 168, 180, 0]),
-            Fix::from_le_bytes([14, 172, 181, 255]),
-            Fix::from_le_bytes([42, 248, 200, 255]),
-            Fix::from_le_bytes([173, 84, 149, 0]),
-            Fix::from_le_bytes([28, 24, 3, 0]),
-            Fix::from_le_bytes([221, 168, 154, 255]),
-            Fix::from_le_bytes([253, 148, 252, 255]),
-            Fix::from_le_bytes([231, 230, 243, 255]),
-            Fix::from_le_bytes([109, 206, 214, 255]),
-            Fix::from_le_bytes([4, 101, 18, 0]),
-            Fix::from_le_bytes([92, 180, 217, 255]),
-            Fix::from_le_bytes([128, 142, 173, 255]),
-            Fix::from_le_bytes([147, 201, 44, 0]),
-            Fix::from_le_bytes([253, 183, 132, 0]),
-        ],
-        [
-            Fix::from_le_bytes([53, 184, 61, 0]),
-            Fix::from_le_bytes([183, 249, 248, 255]),
-            Fix::from_le_bytes([93, 18, 62, 0]),
-            Fix::from_le_bytes([176, 188, 7, 0]),
-            Fix::from_le_bytes([224, 249, 162, 255]),
-            Fix::from_le_bytes([138, 144, 212, 255]),
-            Fix::from_le_bytes([212, 153, 215, 255]),
-            Fix::from_le_bytes([222, 15, 63, 0]),
-            Fix::from_le_bytes([23, 233, 32, 0]),
-            Fix::from_le_bytes([192, 101, 23, 0]),
-            Fix::from_le_bytes([238, 152, 38, 0]),
-            Fix::from_le_bytes([208, 49, 23, 0]),
-            Fix::from_le_bytes([4, 200, 159, 255]),
-            Fix::from_le_bytes([214, 22, 182, 255]),
-            Fix::from_le_bytes([110, 28, 165, 0]),
-            Fix::from_le_bytes([173, 186, 151, 255]),
-            Fix::from_le_bytes([75, 201, 17, 0]),
-            Fix::from_le_bytes([3, 1, 20, 0]),
-            Fix::from_le_bytes([12, 144, 220, 255]),
-            Fix::from_le_bytes([13, 171, 246, 255]),
-        ],
-        [
-            Fix::from_le_bytes([198, 127, 37, 0]),
-            Fix::from_le_bytes([225, 125, 244, 255]),
-            Fix::from_le_bytes([184, 227, 124, 255]),
-            Fix::from_le_bytes([123, 181, 146, 0]),
-            Fix::from_le_bytes([200, 226, 246, 255]),
-            Fix::from_le_bytes([91, 146, 240, 255]),
-            Fix::from_le_bytes([126, 204, 37, 0]),
-            Fix::from_le_bytes([240, 119, 244, 255]),
-            Fix::from_le_bytes([167, 112, 11, 0]),
-            Fix::from_le_bytes([79, 42, 162, 0]),
-            Fix::from_le_bytes([59, 141, 231, 255]),
-            Fix::from_le_bytes([16, 158, 102, 0]),
-            Fix::from_le_bytes([36, 161, 7, 0]),
-            Fix::from_le_bytes([124, 188, 5, 0]),
-            Fix::from_le_bytes([192, 79, 17, 0]),
-            Fix::from_le_bytes([24, 151, 184, 255]),
-            Fix::from_le_bytes([252, 204, 32, 0]),
-            Fix::from_le_bytes([8, 68, 163, 255]),
-            Fix::from_le_bytes([234, 134, 218, 255]),
-            Fix::from_le_bytes([212, 185, 88, 0]),
-        ],
-        [
-            Fix::from_le_bytes([142, 157, 243, 255]),
-            Fix::from_le_bytes([124, 165, 191, 255]),
-            Fix::from_le_bytes([14, 43, 186, 255]),
-            Fix::from_le_bytes([136, 62, 199, 255]),
-            Fix::from_le_bytes([224, 18, 203, 255]),
-            Fix::from_le_bytes([51, 214, 247, 255]),
-            Fix::from_le_bytes([172, 38, 10, 0]),
-            Fix::from_le_bytes([212, 242, 40, 0]),
-            Fix::from_le_bytes([74, 37, 232, 255]),
-            Fix::from_le_bytes([18, 151, 232, 255]),
-            Fix::from_le_bytes([103, 38, 233, 255]),
-            Fix::from_le_bytes([65, 162, 219, 255]),
-            Fix::from_le_bytes([250, 39, 247, 255]),
-            Fix::from_le_bytes([128, 127, 215, 255]),
-            Fix::from_le_bytes([136, 24, 220, 255]),
-            Fix::from_le_bytes([65, 105, 237, 255]),
-            Fix::from_le_bytes([30, 72, 44, 0]),
-            Fix::from_le_bytes([173, 168, 208, 255]),
-            Fix::from_le_bytes([9, 254, 21, 0]),
-            Fix::from_le_bytes([102, 229, 68, 0]),
-        ],
-        [
-            Fix::from_le_bytes([139, 36, 10, 0]),
-            Fix::from_le_bytes([180, 153, 252, 255]),
-            Fix::from_le_bytes([44, 143, 18, 0]),
-            Fix::from_le_bytes([168, 192, 221, 255]),
-            Fix::from_le_bytes([82, 65, 229, 255]),
-            Fix::from_le_bytes([252, 216, 2, 0]),
-            Fix::from_le_bytes([180, 21, 151, 255]),
-            Fix::from_le_bytes([19, 71, 222, 255]),
-            Fix::from_le_bytes([174, 112, 24, 0]),
-            Fix::from_le_bytes([5, 228, 39, 0]),
-            Fix::from_le_bytes([234, 92, 197, 255]),
-            Fix::from_le_bytes([205, 114, 10, 0]),
-            Fix::from_le_bytes([35, 229, 9, 0]),
-            Fix::from_le_bytes([116, 161, 232, 255]),
-            Fix::from_le_bytes([70, 199, 221, 255]),
-            Fix::from_le_bytes([80, 145, 34, 0]),
-            Fix::from_le_bytes([58, 4, 189, 255]),
-            Fix::from_le_bytes([94, 154, 233, 255]),
-            Fix::from_le_bytes([112, 98, 197, 255]),
-            Fix::from_le_bytes([165, 131, 34, 0]),
-        ],
-        [
-            Fix::from_le_bytes([27, 230, 55, 0]),
-            Fix::from_le_bytes([244, 211, 176, 255]),
-            Fix::from_le_bytes([12, 23, 106, 0]),
-            Fix::from_le_bytes([210, 195, 237, 255]),
-            Fix::from_le_bytes([15, 203, 96, 0]),
-            Fix::from_le_bytes([109, 109, 223, 255]),
-            Fix::from_le_bytes([188, 164, 19, 0]),
-            Fix::from_le_bytes([120, 128, 39, 0]),
-            Fix::from_le_bytes([123, 33, 28, 0]),
-            Fix::from_le_bytes([154, 221, 211, 255]),
-            Fix::from_le_bytes([114, 253, 204, 255]),
-            Fix::from_le_bytes([126, 0, 1, 0]),
-            Fix::from_le_bytes([161, 52, 232, 255]),
-            Fix::from_le_bytes([30, 118, 233, 255]),
-            Fix::from_le_bytes([16, 77, 182, 255]),
-            Fix::from_le_bytes([239, 234, 100, 0]),
-            Fix::from_le_bytes([150, 216, 53, 0]),
-            Fix::from_le_bytes([164, 206, 54, 0]),
-            Fix::from_le_bytes([140, 41, 252, 255]),
-            Fix::from_le_bytes([144, 176, 166, 255]),
-        ],
-        [
-            Fix::from_le_bytes([226, 176, 8, 0]),
-            Fix::from_le_bytes([181, 149, 238, 255]),
-            Fix::from_le_bytes([228, 99, 110, 0]),
-            Fix::from_le_bytes([110, 245, 171, 255]),
-            Fix::from_le_bytes([106, 137, 112, 0]),
-            Fix::from_le_bytes([212, 107, 148, 255]),
-            Fix::from_le_bytes([196, 235, 248, 255]),
-            Fix::from_le_bytes([135, 65, 221, 255]),
-            Fix::from_le_bytes([139, 4, 156, 0]),
-            Fix::from_le_bytes([128, 19, 214, 255]),
-            Fix::from_le_bytes([242, 26, 7, 0]),
-            Fix::from_le_bytes([234, 250, 65, 0]),
-            Fix::from_le_bytes([66, 34, 177, 0]),
-            Fix::from_le_bytes([60, 18, 133, 0]),
-            Fix::from_le_bytes([49, 98, 210, 255]),
-            Fix::from_le_bytes([54, 16, 85, 0]),
-            Fix::from_le_bytes([76, 147, 76, 0]),
-            Fix::from_le_bytes([47, 5, 247, 255]),
-            Fix::from_le_bytes([124, 172, 47, 0]),
-            Fix::from_le_bytes([94, 81, 37, 0]),
-        ],
-        [
-            Fix::from_le_bytes([30, 181, 32, 0]),
-            Fix::from_le_bytes([116, 151, 59, 0]),
-            Fix::from_le_bytes([250, 50, 55, 0]),
-            Fix::from_le_bytes([38, 42, 32, 0]),
-            Fix::from_le_bytes([75, 125, 159, 255]),
-            Fix::from_le_bytes([235, 7, 7, 0]),
-            Fix::from_le_bytes([67, 52, 205, 255]),
-            Fix::from_le_bytes([20, 83, 59, 0]),
-            Fix::from_le_bytes([71, 233, 252, 255]),
-            Fix::from_le_bytes([91, 170, 199, 255]),
-            Fix::from_le_bytes([191, 25, 218, 255]),
-            Fix::from_le_bytes([110, 135, 121, 0]),
-            Fix::from_le_bytes([190, 166, 237, 255]),
-            Fix::from_le_bytes([46, 225, 49, 0]),
-            Fix::from_le_bytes([54, 118, 5, 0]),
-            Fix::from_le_bytes([126, 194, 254, 255]),
-            Fix::from_le_bytes([16, 252, 55, 0]),
-            Fix::from_le_bytes([208, 171, 218, 255]),
-            Fix::from_le_bytes([203, 27, 240, 255]),
-            Fix::from_le_bytes([109, 240, 238, 255]),
-        ],
-        [
-            Fix::from_le_bytes([142, 252, 84, 0]),
-            Fix::from_le_bytes([6, 100, 185, 255]),
-            Fix::from_le_bytes([138, 215, 70, 0]),
-            Fix::from_le_bytes([117, 9, 9, 0]),
-            Fix::from_le_bytes([59, 250, 186, 255]),
-            Fix::from_le_bytes([242, 130, 72, 0]),
-            Fix::from_le_bytes([239, 89, 219, 255]),
-            Fix::from_le_bytes([108, 81, 11, 0]),
-            Fix::from_le_bytes([130, 165, 226, 255]),
-            Fix::from_le_bytes([119, 161, 11, 0]),
-            Fix::from_le_bytes([72, 41, 5, 0]),
-            Fix::from_le_bytes([172, 196, 240, 255]),
-            Fix::from_le_bytes([186, 252, 241, 255]),
-            Fix::from_le_bytes([69, 82, 248, 255]),
-            Fix::from_le_bytes([49, 49, 197, 0]),
-            Fix::from_le_bytes([129, 154, 255, 255]),
-            Fix::from_le_bytes([106, 150, 255, 255]),
-            Fix::from_le_bytes([107, 16, 239, 255]),
-            Fix::from_le_bytes([37, 201, 65, 0]),
-            Fix::from_le_bytes([53, 93, 124, 255]),
-        ],
-        [
-            Fix::from_le_bytes([74, 10, 113, 0]),
-            Fix::from_le_bytes([12, 187, 224, 255]),
-            Fix::from_le_bytes([224, 195, 253, 255]),
-            Fix::from_le_bytes([123, 73, 248, 255]),
-            Fix::from_le_bytes([22, 173, 187, 255]),
-            Fix::from_le_bytes([221, 191, 134, 0]),
-            Fix::from_le_bytes([171, 193, 166, 0]),
-            Fix::from_le_bytes([130, 161, 177, 255]),
-            Fix::from_le_bytes([44, 58, 222, 255]),
-            Fix::from_le_bytes([202, 204, 84, 0]),
-            Fix::from_le_bytes([116, 253, 201, 255]),
-            Fix::from_le_bytes([43, 76, 161, 255]),
-            Fix::from_le_bytes([26, 142, 184, 255]),
-            Fix::from_le_bytes([14, 55, 207, 255]),
-            Fix::from_le_bytes([42, 72, 62, 0]),
-            Fix::from_le_bytes([244, 121, 162, 255]),
-            Fix::from_le_bytes([152, 161, 186, 255]),
-            Fix::from_le_bytes([250, 192, 83, 0]),
-            Fix::from_le_bytes([101, 168, 35, 0]),
-            Fix::from_le_bytes([156, 18, 37, 0]),
-        ],
-        [
-            Fix::from_le_bytes([196, 169, 61, 0]),
-            Fix::from_le_bytes([234, 93, 30, 0]),
-            Fix::from_le_bytes([200, 203, 51, 0]),
-            Fix::from_le_bytes([248, 87, 20, 0]),
-            Fix::from_le_bytes([117, 108, 227, 255]),
-            Fix::from_le_bytes([241, 42, 67, 0]),
-            Fix::from_le_bytes([152, 202, 59, 0]),
-            Fix::from_le_bytes([145, 111, 5, 0]),
-            Fix::from_le_bytes([228, 228, 119, 0]),
-            Fix::from_le_bytes([112, 205, 202, 255]),
-            Fix::from_le_bytes([3, 213, 203, 255]),
-            Fix::from_le_bytes([104, 3, 158, 255]),
-            Fix::from_le_bytes([12, 192, 21, 0]),
-            Fix::from_le_bytes([113, 135, 193, 255]),
-            Fix::from_le_bytes([102, 205, 89, 0]),
-            Fix::from_le_bytes([130, 247, 39, 0]),
-            Fix::from_le_bytes([204, 147, 23, 0]),
-            Fix::from_le_bytes([52, 66, 10, 0]),
-            Fix::from_le_bytes([131, 136, 26, 0]),
-            Fix::from_le_bytes([89, 18, 151, 255]),
-        ],
-        [
-            Fix::from_le_bytes([141, 121, 18, 0]),
-            Fix::from_le_bytes([128, 250, 223, 255]),
-            Fix::from_le_bytes([156, 252, 14, 0]),
-            Fix::from_le_bytes([134, 93, 83, 0]),
-            Fix::from_le_bytes([25, 129, 72, 0]),
-            Fix::from_le_bytes([122, 92, 178, 255]),
-            Fix::from_le_bytes([38, 39, 126, 255]),
-            Fix::from_le_bytes([170, 101, 60, 0]),
-            Fix::from_le_bytes([114, 247, 114, 0]),
-            Fix::from_le_bytes([233, 244, 177, 255]),
-            Fix::from_le_bytes([222, 169, 44, 0]),
-            Fix::from_le_bytes([12, 98, 73, 0]),
-            Fix::from_le_bytes([136, 129, 38, 0]),
-            Fix::from_le_bytes([238, 200, 191, 0]),
-            Fix::from_le_bytes([164, 160, 228, 255]),
-            Fix::from_le_bytes([58, 239, 75, 0]),
-            Fix::from_le_bytes([67, 224, 14, 0]),
-            Fix::from_le_bytes([166, 215, 175, 255]),
-            Fix::from_le_bytes([142, 211, 4, 0]),
-            Fix::from_le_bytes([205, 135, 118, 0]),
-        ],
-        [
-            Fix::from_le_bytes([130, 129, 229, 255]),
-            Fix::from_le_bytes([113, 55, 204, 255]),
-            Fix::from_le_bytes([132, 74, 232, 255]),
-            Fix::from_le_bytes([90, 203, 66, 0]),
-            Fix::from_le_bytes([5, 59, 186, 255]),
-            Fix::from_le_bytes([118, 124, 121, 0]),
-            Fix::from_le_bytes([103, 63, 190, 0]),
-            Fix::from_le_bytes([129, 11, 212, 255]),
-            Fix::from_le_bytes([197, 93, 250, 255]),
-            Fix::from_le_bytes([238, 13, 83, 0]),
-            Fix::from_le_bytes([15, 3, 31, 0]),
-            Fix::from_le_bytes([207, 246, 3, 0]),
-            Fix::from_le_bytes([254, 182, 149, 255]),
-            Fix::from_le_bytes([126, 42, 231, 255]),
-            Fix::from_le_bytes([26, 180, 110, 0]),
-            Fix::from_le_bytes([20, 194, 191, 255]),
-            Fix::from_le_bytes([142, 133, 182, 255]),
-            Fix::from_le_bytes([212, 20, 203, 255]),
-            Fix::from_le_bytes([175, 244, 166, 255]),
-            Fix::from_le_bytes([216, 104, 41, 0]),
-        ],
-        [
-            Fix::from_le_bytes([199, 201, 58, 0]),
-            Fix::from_le_bytes([91, 55, 16, 0]),
-            Fix::from_le_bytes([152, 209, 7, 0]),
-            Fix::from_le_bytes([50, 223, 1, 0]),
-            Fix::from_le_bytes([6, 93, 2, 0]),
-            Fix::from_le_bytes([61, 108, 9, 0]),
-            Fix::from_le_bytes([92, 148, 253, 255]),
-            Fix::from_le_bytes([239, 126, 242, 255]),
-            Fix::from_le_bytes([22, 69, 227, 255]),
-            Fix::from_le_bytes([177, 226, 10, 0]),
-            Fix::from_le_bytes([80, 252, 64, 0]),
-            Fix::from_le_bytes([102, 65, 182, 255]),
-            Fix::from_le_bytes([209, 71, 11, 0]),
-            Fix::from_le_bytes([10, 133, 247, 255]),
-            Fix::from_le_bytes([88, 244, 176, 255]),
-            Fix::from_le_bytes([176, 211, 176, 255]),
-            Fix::from_le_bytes([24, 156, 234, 255]),
-            Fix::from_le_bytes([112, 253, 216, 255]),
-            Fix::from_le_bytes([30, 119, 219, 255]),
-            Fix::from_le_bytes([205, 124, 210, 255]),
-        ],
-        [
-            Fix::from_le_bytes([13, 56, 33, 0]),
-            Fix::from_le_bytes([112, 77, 64, 0]),
-            Fix::from_le_bytes([252, 65, 199, 255]),
-            Fix::from_le_bytes([56, 251, 179, 255]),
-            Fix::from_le_bytes([48, 203, 45, 0]),
-            Fix::from_le_bytes([177, 151, 229, 255]),
-            Fix::from_le_bytes([234, 148, 4, 0]),
-            Fix::from_le_bytes([15, 218, 27, 0]),
-            Fix::from_le_bytes([20, 178, 41, 0]),
-            Fix::from_le_bytes([11, 8, 67, 0]),
-            Fix::from_le_bytes([229, 67, 22, 0]),
-            Fix::from_le_bytes([158, 232, 18, 0]),
-            Fix::from_le_bytes([143, 167, 198, 255]),
-            Fix::from_le_bytes([164, 114, 79, 0]),
-            Fix::from_le_bytes([68, 99, 234, 255]),
-            Fix::from_le_bytes([127, 198, 60, 0]),
-            Fix::from_le_bytes([167, 224, 1, 0]),
-            Fix::from_le_bytes([160, 113, 204, 255]),
-            Fix::from_le_bytes([25, 188, 15, 0]),
-            Fix::from_le_bytes([21, 145, 11, 0]),
-        ],
-    ],
-    biases: [
-        Fix::from_le_bytes([73, 64, 19, 0]),
-        Fix::from_le_bytes([78, 74, 93, 0]),
-        Fix::from_le_bytes([76, 81, 49, 0]),
-        Fix::from_le_bytes([133, 237, 14, 0]),
-        Fix::from_le_bytes([236, 236, 51, 0]),
-        Fix::from_le_bytes([50, 127, 20, 0]),
-        Fix::from_le_bytes([151, 41, 38, 0]),
-        Fix::from_le_bytes([216, 175, 43, 0]),
-        Fix::from_le_bytes([187, 109, 0, 0]),
-        Fix::from_le_bytes([4, 124, 2, 0]),
-        Fix::from_le_bytes([4, 229, 14, 0]),
-        Fix::from_le_bytes([138, 213, 51, 0]),
-        Fix::from_le_bytes([142, 238, 39, 0]),
-        Fix::from_le_bytes([106, 73, 76, 0]),
-        Fix::from_le_bytes([210, 4, 9, 0]),
-        Fix::from_le_bytes([184, 190, 51, 0]),
-        Fix::from_le_bytes([223, 7, 38, 0]),
-        Fix::from_le_bytes([136, 114, 67, 0]),
-        Fix::from_le_bytes([250, 115, 0, 0]),
-        Fix::from_le_bytes([44, 92, 7, 0]),
-    ],
-},
-	layer2: KartoffelLayer {
-    weights: [
-        [
-            Fix::from_le_bytes([16, 59, 61, 0]),
-            Fix::from_le_bytes([205, 203, 217, 255]),
-            Fix::from_le_bytes([252, 202, 57, 0]),
-            Fix::from_le_bytes([121, 133, 83, 255]),
-            Fix::from_le_bytes([180, 135, 229, 255]),
-            Fix::from_le_bytes([244, 114, 140, 0]),
-            Fix::from_le_bytes([2, 44, 43, 0]),
-            Fix::from_le_bytes([45, 26, 16, 0]),
-            Fix::from_le_bytes([189, 157, 0, 0]),
-            Fix::from_le_bytes([68, 244, 0, 0]),
-            Fix::from_le_bytes([226, 62, 208, 255]),
-            Fix::from_le_bytes([192, 134, 78, 255]),
-            Fix::from_le_bytes([75, 216, 176, 255]),
-            Fix::from_le_bytes([152, 222, 67, 0]),
-            Fix::from_le_bytes([64, 254, 190, 0]),
-            Fix::from_le_bytes([16, 217, 68, 0]),
-            Fix::from_le_bytes([125, 26, 46, 255]),
-            Fix::from_le_bytes([177, 94, 141, 0]),
-            Fix::from_le_bytes([44, 81, 255, 255]),
-            Fix::from_le_bytes([4, 148, 204, 255]),
-        ],
-        [
-            Fix::from_le_bytes([115, 179, 29, 0]),
-            Fix::from_le_bytes([62, 113, 169, 255]),
-            Fix::from_le_bytes([197, 57, 116, 255]),
-            Fix::from_le_bytes([232, 34, 102, 0]),
-            Fix::from_le_bytes([233, 85, 28, 0]),
-            Fix::from_le_bytes([118, 245, 95, 0]),
-            Fix::from_le_bytes([69, 249, 82, 255]),
-            Fix::from_le_bytes([98, 133, 123, 0]),
-            Fix::from_le_bytes([235, 116, 2, 0]),
-            Fix::from_le_bytes([17, 137, 8, 0]),
-            Fix::from_le_bytes([98, 168, 203, 255]),
-            Fix::from_le_bytes([100, 203, 89, 0]),
-            Fix::from_le_bytes([6, 120, 241, 255]),
-            Fix::from_le_bytes([71, 92, 59, 255]),
-            Fix::from_le_bytes([235, 67, 229, 255]),
-            Fix::from_le_bytes([73, 253, 117, 255]),
-            Fix::from_le_bytes([105, 178, 121, 0]),
-            Fix::from_le_bytes([2, 43, 1, 0]),
-            Fix::from_le_bytes([98, 38, 0, 0]),
-            Fix::from_le_bytes([147, 95, 58, 0]),
-        ],
-        [
-            Fix::from_le_bytes([49, 162, 246, 255]),
-            Fix::from_le_bytes([208, 75, 230, 255]),
-            Fix::from_le_bytes([160, 24, 219, 255]),
-            Fix::from_le_bytes([11, 53, 239, 255]),
-            Fix::from_le_bytes([180, 4, 247, 255]),
-            Fix::from_le_bytes([102, 202, 226, 255]),
-            Fix::from_le_bytes([233, 118, 243, 255]),
-            Fix::from_le_bytes([213, 221, 233, 255]),
-            Fix::from_le_bytes([121, 237, 254, 255]),
-            Fix::from_le_bytes([47, 136, 253, 255]),
-            Fix::from_le_bytes([75, 194, 248, 255]),
-            Fix::from_le_bytes([222, 108, 214, 255]),
-            Fix::from_le_bytes([189, 44, 241, 255]),
-            Fix::from_le_bytes([63, 71, 215, 255]),
-            Fix::from_le_bytes([179, 142, 223, 255]),
-            Fix::from_le_bytes([32, 186, 218, 255]),
-            Fix::from_le_bytes([73, 83, 221, 255]),
-            Fix::from_le_bytes([114, 18, 229, 255]),
-            Fix::from_le_bytes([160, 251, 254, 255]),
-            Fix::from_le_bytes([148, 54, 1, 0]),
-        ],
-        [
-            Fix::from_le_bytes([150, 122, 197, 255]),
-            Fix::from_le_bytes([71, 82, 183, 0]),
-            Fix::from_le_bytes([73, 219, 215, 255]),
-            Fix::from_le_bytes([76, 141, 5, 0]),
-            Fix::from_le_bytes([49, 245, 42, 0]),
-            Fix::from_le_bytes([130, 159, 103, 255]),
-            Fix::from_le_bytes([102, 23, 104, 0]),
-            Fix::from_le_bytes([232, 66, 41, 0]),
-            Fix::from_le_bytes([161, 130, 0, 0]),
-            Fix::from_le_bytes([86, 171, 253, 255]),
-            Fix::from_le_bytes([210, 158, 179, 255]),
-            Fix::from_le_bytes([24, 175, 183, 255]),
-            Fix::from_le_bytes([96, 24, 88, 0]),
-            Fix::from_le_bytes([17, 135, 80, 0]),
-            Fix::from_le_bytes([182, 152, 188, 255]),
-            Fix::from_le_bytes([41, 148, 11, 0]),
-            Fix::from_le_bytes([6, 42, 68, 0]),
-            Fix::from_le_bytes([24, 77, 26, 0]),
-            Fix::from_le_bytes([149, 73, 255, 255]),
-            Fix::from_le_bytes([217, 10, 238, 255]),
-        ],
-        [
-            Fix::from_le_bytes([104, 243, 233, 255]),
-            Fix::from_le_bytes([56, 71, 157, 255]),
-            Fix::from_le_bytes([252, 160, 147, 0]),
-            Fix::from_le_bytes([1, 157, 48, 0]),
-            Fix::from_le_bytes([207, 93, 213, 255]),
-            Fix::from_le_bytes([237, 150, 196, 255]),
-            Fix::from_le_bytes([148, 81, 232, 255]),
-            Fix::from_le_bytes([230, 114, 67, 255]),
-            Fix::from_le_bytes([217, 161, 252, 255]),
-            Fix::from_le_bytes([84, 86, 253, 255]),
-            Fix::from_le_bytes([234, 250, 143, 0]),
-            Fix::from_le_bytes([33, 66, 137, 0]),
-            Fix::from_le_bytes([123, 126, 6, 0]),
-            Fix::from_le_bytes([220, 242, 44, 0]),
-            Fix::from_le_bytes([180, 125, 157, 255]),
-            Fix::from_le_bytes([178, 65, 81, 0]),
-            Fix::from_le_bytes([199, 44, 28, 0]),
-            Fix::from_le_bytes([166, 146, 43, 255]),
-            Fix::from_le_bytes([198, 242, 1, 0]),
-            Fix::from_le_bytes([46, 226, 4, 0]),
-        ],
-        [
-            Fix::from_le_bytes([159, 151, 250, 255]),
-            Fix::from_le_bytes([101, 195, 2, 0]),
-            Fix::from_le_bytes([224, 204, 243, 255]),
-            Fix::from_le_bytes([143, 153, 233, 255]),
-            Fix::from_le_bytes([204, 39, 4, 0]),
-            Fix::from_le_bytes([58, 225, 226, 255]),
-            Fix::from_le_bytes([55, 156, 252, 255]),
-            Fix::from_le_bytes([246, 235, 230, 255]),
-            Fix::from_le_bytes([25, 130, 0, 0]),
-            Fix::from_le_bytes([6, 22, 253, 255]),
-            Fix::from_le_bytes([96, 40, 5, 0]),
-            Fix::from_le_bytes([162, 86, 220, 255]),
-            Fix::from_le_bytes([156, 169, 5, 0]),
-            Fix::from_le_bytes([255, 219, 235, 255]),
-            Fix::from_le_bytes([32, 8, 226, 255]),
-            Fix::from_le_bytes([53, 237, 238, 255]),
-            Fix::from_le_bytes([42, 128, 223, 255]),
-            Fix::from_le_bytes([80, 210, 236, 255]),
-            Fix::from_le_bytes([88, 95, 0, 0]),
-            Fix::from_le_bytes([243, 235, 250, 255]),
-        ],
-    ],
-    biases: [
-        Fix::from_le_bytes([28, 118, 16, 0]),
-        Fix::from_le_bytes([74, 61, 24, 0]),
-        Fix::from_le_bytes([2, 187, 206, 255]),
-        Fix::from_le_bytes([111, 38, 23, 0]),
-        Fix::from_le_bytes([111, 239, 9, 0]),
-        Fix::from_le_bytes([237, 69, 215, 255]),
-    ],
-},
-};
\ No newline at end of file
+/// Feeds the same input into both `A` and `B`, e.g. the heads of a model
+/// sharing a trunk: `Chain<Trunk, Fork<Policy, Fork<Value, EnemyNearby>>>`.
+pub struct Fork<A, B>(pub A, pub B);
+
+impl<A, B> Layer for Fork<A, B>
+where
+    A: Layer,
+    B: Layer<Input = A::Input>,
+{
+    type Input = A::Input;
+    type Output = (A::Output, B::Output);
+    type State = (A::State, B::State);
+
+    const INITIAL_STATE: Self::State = (A::INITIAL_STATE, B::INITIAL_STATE);
+
+    fn forward(&self, input: &A::Input, state: &mut Self::State) -> Self::Output {
+        (self.0.forward(input, &mut state.0), self.1.forward(input, &mut state.1))
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        self.0.overflows(f);
+        self.1.overflows(f);
+    }
+}
+
+/// Adds the input of `L` to its output - a skip connection around a block of
+/// layers that keeps the width, letting deeper models train.
+pub struct Residual<L, const LEN: usize> {
+    block: L,
+    overflows: AtomicU32,
+}
+
+impl<L, const LEN: usize> Residual<L, LEN> {
+    #[cfg_attr(not(kartoffel_layer = "Residual"), allow(dead_code))]
+    pub const fn new(block: L) -> Self {
+        Residual { block, overflows: AtomicU32::new(0) }
+    }
+}
+
+impl<L, const LEN: usize> Layer for Residual<L, LEN>
+where
+    L: Layer<Input = [Fix; LEN], Output = [Fix; LEN]>,
+{
+    type Input = [Fix; LEN];
+    type Output = [Fix; LEN];
+    type State = L::State;
+
+    const INITIAL_STATE: L::State = L::INITIAL_STATE;
+
+    fn forward(&self, x: &[Fix; LEN], state: &mut L::State) -> [Fix; LEN] {
+        let y = self.block.forward(x, state);
+        core::array::from_fn(|i| narrow(x[i].widen() + y[i].widen(), &self.overflows))
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        self.block.overflows(f);
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
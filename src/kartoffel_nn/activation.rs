@@ -0,0 +1,235 @@
+use super::{format, Fix, Num};
+
+pub trait Activation {
+    const ID: u8;
+
+    /// Activation-specific parameter stored next to `ID` in the model blob.
+    const PARAM: i32 = 0;
+
+    const INSTANCE: Self;
+
+    fn apply<T: Num>(&self, x: T) -> T;
+}
+
+pub struct Id;
+
+impl Activation for Id {
+    const ID: u8 = format::ACTIVATION_ID;
+    const INSTANCE: Self = Id;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        x
+    }
+}
+
+pub struct Relu;
+
+impl Activation for Relu {
+    const ID: u8 = format::ACTIVATION_RELU;
+    const INSTANCE: Self = Relu;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        if x > T::ZERO { x } else { T::ZERO }
+    }
+}
+
+/// `x` for positive inputs, `x * SLOPE` otherwise; `SLOPE` is the bit pattern
+/// of a `Fix`.
+pub struct LeakyRelu<const SLOPE: i32>;
+
+impl<const SLOPE: i32> Activation for LeakyRelu<SLOPE> {
+    const ID: u8 = format::ACTIVATION_LEAKY_RELU;
+    const PARAM: i32 = SLOPE;
+    const INSTANCE: Self = LeakyRelu;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        if x >= T::ZERO {
+            x
+        } else {
+            x * T::from_fix(Fix::from_bits(SLOPE))
+        }
+    }
+}
+
+/// `x` clamped to -1..=1.
+pub struct HardTanh;
+
+impl Activation for HardTanh {
+    const ID: u8 = format::ACTIVATION_HARD_TANH;
+    const INSTANCE: Self = HardTanh;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        clamp(x, -T::ONE, T::ONE)
+    }
+}
+
+/// `x / 6 + 1/2` clamped to 0..=1, same as PyTorch's `Hardsigmoid`.
+pub struct HardSigmoid;
+
+impl Activation for HardSigmoid {
+    const ID: u8 = format::ACTIVATION_HARD_SIGMOID;
+    const INSTANCE: Self = HardSigmoid;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        clamp(x.div_int(6) + T::ONE.div_int(2), T::ZERO, T::ONE)
+    }
+}
+
+/// On `Fix`, interpolates tanh between points of `TANH_LUT`; off by at most
+/// `TANH_MAX_ERROR`.
+pub struct Tanh;
+
+impl Activation for Tanh {
+    const ID: u8 = format::ACTIVATION_TANH;
+    const INSTANCE: Self = Tanh;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        x.tanh()
+    }
+}
+
+/// Computed as `(1 + tanh(x / 2)) / 2`; on `Fix`, off by at most
+/// `TANH_MAX_ERROR / 2`.
+pub struct Sigmoid;
+
+impl Activation for Sigmoid {
+    const ID: u8 = format::ACTIVATION_SIGMOID;
+    const INSTANCE: Self = Sigmoid;
+
+    fn apply<T: Num>(&self, x: T) -> T {
+        (T::ONE + x.div_int(2).tanh()).div_int(2)
+    }
+}
+
+fn clamp<T: Num>(x: T, min: T, max: T) -> T {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+// The tests check `Tanh` and `Sigmoid` against these
+#[cfg_attr(not(test), allow(dead_code))]
+pub const TANH_MAX_ERROR: f64 = 1e-3;
+
+/// tanh at `0, 1/2^TANH_LUT_STEP_BITS, ..., TANH_LUT_MAX`; past that, tanh is
+/// within `TANH_MAX_ERROR` from 1.
+const TANH_LUT: [Fix; TANH_LUT_LEN] = tanh_lut();
+const TANH_LUT_MAX: usize = 4;
+const TANH_LUT_STEP_BITS: u32 = 4;
+const TANH_LUT_LEN: usize = (TANH_LUT_MAX << TANH_LUT_STEP_BITS) + 1;
+
+pub(super) fn tanh(x: Fix) -> Fix {
+    let abs = x.unsigned_abs().to_bits();
+    let shift = Fix::FRAC_NBITS - TANH_LUT_STEP_BITS;
+    let i = (abs >> shift) as usize;
+
+    let y = if i + 1 >= TANH_LUT_LEN {
+        Fix::ONE
+    } else {
+        let t = Fix::from_bits(((abs & ((1 << shift) - 1)) << TANH_LUT_STEP_BITS) as i32);
+        TANH_LUT[i] + (TANH_LUT[i + 1] - TANH_LUT[i]) * t
+    };
+
+    if x < Fix::ZERO {
+        -y
+    } else {
+        y
+    }
+}
+
+const fn tanh_lut() -> [Fix; TANH_LUT_LEN] {
+    let mut lut = [Fix::ZERO; TANH_LUT_LEN];
+    let mut i = 0;
+    while i < TANH_LUT_LEN {
+        let x = i as f64 / (1 << TANH_LUT_STEP_BITS) as f64;
+        lut[i] = const_from_f64(1.0 - 2.0 / (const_exp(2.0 * x) + 1.0));
+        i += 1;
+    }
+    lut
+}
+
+/// exp() for building lookup tables in const context.
+pub(super) const fn const_exp(x: f64) -> f64 {
+    // exp(x) = exp(x / 2^8)^(2^8), with a few terms of the Taylor series for
+    // the small part
+    let y = x / 256.0;
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1;
+    while n < 12 {
+        term = term * y / n as f64;
+        sum += term;
+        n += 1;
+    }
+
+    let mut i = 0;
+    while i < 8 {
+        sum *= sum;
+        i += 1;
+    }
+    sum
+}
+
+/// Nearest `Fix` to a non-negative `x`, in const context.
+pub(super) const fn const_from_f64(x: f64) -> Fix {
+    Fix::from_bits((x * (1u32 << Fix::FRAC_NBITS) as f64 + 0.5) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A rounding or two, whatever `Fix` is.
+    fn exact() -> f64 {
+        2.0 * Fix::DELTA.to_num::<f64>()
+    }
+
+    /// Compares `actual` against `expected` from -16 to 16, in steps of 1/1024.
+    fn check(name: &str, actual: impl Fn(Fix) -> Fix, expected: impl Fn(f64) -> f64, max_error: f64) {
+        for x in (-16 << 10..=16 << 10).map(|x| Fix::from_bits(x << (Fix::FRAC_NBITS - 10))) {
+            let error = (actual(x).to_num::<f64>() - expected(x.to_num())).abs();
+            assert!(error <= max_error, "{name}({x}) is off by {error}, which is more than {max_error}");
+        }
+    }
+
+    #[test]
+    fn relu() {
+        check("Relu", |x| Relu.apply(x), |x| x.max(0.0), exact());
+    }
+
+    #[test]
+    fn leaky_relu() {
+        const SLOPE: f64 = 0.0625;
+
+        check(
+            "LeakyRelu",
+            |x| LeakyRelu::<{ 1 << (Fix::FRAC_NBITS - 4) }>.apply(x),
+            |x| if x >= 0.0 { x } else { x * SLOPE },
+            exact(),
+        );
+    }
+
+    #[test]
+    fn hard_tanh() {
+        check("HardTanh", |x| HardTanh.apply(x), |x| x.clamp(-1.0, 1.0), exact());
+    }
+
+    #[test]
+    fn hard_sigmoid() {
+        check("HardSigmoid", |x| HardSigmoid.apply(x), |x| (x / 6.0 + 0.5).clamp(0.0, 1.0), exact());
+    }
+
+    #[test]
+    fn tanh() {
+        check("Tanh", |x| Tanh.apply(x), f64::tanh, TANH_MAX_ERROR);
+    }
+
+    #[test]
+    fn sigmoid() {
+        check("Sigmoid", |x| Sigmoid.apply(x), |x| 1.0 / (1.0 + (-x).exp()), TANH_MAX_ERROR / 2.0);
+    }
+}
@@ -0,0 +1,212 @@
+use super::{format, narrow, read_fixes, seek_layer, Activation, Fix, Layer};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Model inputs that can be built out of a bitmask of ones, bit `i` of word
+/// `i / 32` being input `i`; lets the robot pack its observations the same way
+/// no matter which kind of layer the model starts with.
+pub trait BinaryInput {
+    fn from_bitmask(bitmask: &[u32]) -> Self;
+}
+
+impl<const WORDS: usize> BinaryInput for [u32; WORDS] {
+    fn from_bitmask(bitmask: &[u32]) -> Self {
+        bitmask.try_into().expect("bitmask has a wrong number of words")
+    }
+}
+
+impl<const LEN: usize> BinaryInput for [Fix; LEN] {
+    fn from_bitmask(bitmask: &[u32]) -> Self {
+        let mut inputs = [Fix::ZERO; LEN];
+        for i in set_bits(bitmask) {
+            inputs[i] = Fix::ONE;
+        }
+        inputs
+    }
+}
+
+/// Indices of the bits set in `bitmask`, in ascending order.
+pub fn set_bits(bitmask: &[u32]) -> impl Iterator<Item = usize> + '_ {
+    bitmask.iter().enumerate().flat_map(|(i, &word)| word_bits(i, word))
+}
+
+fn word_bits(index: usize, mut word: u32) -> impl Iterator<Item = usize> {
+    core::iter::from_fn(move || {
+        if word == 0 {
+            return None;
+        }
+        let bit = word.trailing_zeros() as usize;
+        word &= word - 1;
+        Some(index * 32 + bit)
+    })
+}
+
+/// Adds the weights of the inputs set in `bitmask` to pre-activations `z`;
+/// `weights[i]` holds input `i`'s weight for every neuron.
+///
+/// For inputs of zero and one this is exactly what `neuron` computes, since
+/// multiplying by `Fix::ONE` doesn't round - just without the multiplications.
+pub fn binary_neurons<W: AsRef<[Fix]>>(z: &mut [i64], bitmask: &[u32], weights: &[W]) {
+    for i in set_bits(bitmask) {
+        for (z, w) in z.iter_mut().zip(weights[i].as_ref()) {
+            *z += w.to_bits() as i64;
+        }
+    }
+}
+
+/// Moves pre-activations `z` from inputs `previous` to inputs `bitmask`, adding
+/// the weights of the inputs that got set and subtracting the ones that got
+/// cleared; gives exactly the same result as computing them from scratch.
+pub fn binary_delta<W: AsRef<[Fix]>>(z: &mut [i64], previous: &[u32], bitmask: &[u32], weights: &[W]) {
+    for (word, (&previous, &current)) in previous.iter().zip(bitmask).enumerate() {
+        for i in word_bits(word, previous ^ current) {
+            let set = current & (1 << (i % 32)) != 0;
+            for (z, w) in z.iter_mut().zip(weights[i].as_ref()) {
+                if set {
+                    *z += w.to_bits() as i64;
+                } else {
+                    *z -= w.to_bits() as i64;
+                }
+            }
+        }
+    }
+}
+
+/// Whether `binary_delta` from `previous` touches fewer weights than
+/// `binary_neurons` for `bitmask` would.
+pub fn delta_is_cheaper(previous: &[u32], bitmask: &[u32]) -> bool {
+    let changed: u32 = previous.iter().zip(bitmask).map(|(p, b)| (p ^ b).count_ones()).sum();
+    let set: u32 = bitmask.iter().map(|b| b.count_ones()).sum();
+    changed < set
+}
+
+/// `Dense` over inputs that are all either zero or one, packed into `WORDS`
+/// words of a bitmask; reads the same blob layout as `Dense`.
+///
+/// Consecutive radar scans tend to differ in just a few cells, so the layer
+/// remembers its last inputs and pre-activations and, when it's cheaper, only
+/// applies the inputs that changed since then.
+pub struct BinaryDense<const PREV_LEN: usize, const LEN: usize, const WORDS: usize, A> {
+    /// Transposed compared to `Dense`, so that every set bit adds a row.
+    weights: [[Fix; LEN]; PREV_LEN],
+    biases: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const WORDS: usize, A: Activation> BinaryDense<PREV_LEN, LEN, WORDS, A> {
+    #[cfg_attr(not(kartoffel_layer = "BinaryDense"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        assert!(WORDS == PREV_LEN.div_ceil(32), "binary layer's bitmask has a wrong number of words");
+
+        let mut data = seek_layer::<A>(blob, index, format::KIND_DENSE, PREV_LEN, LEN);
+
+        let mut weights = [[Fix::ZERO; LEN]; PREV_LEN];
+        let mut i = 0;
+        while i < LEN {
+            let mut j = 0;
+            while j < PREV_LEN {
+                weights[j][i] = Fix::from_bits(data.i32());
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let biases = read_fixes(&mut data);
+
+        BinaryDense { weights, biases, activation: A::INSTANCE, overflows: AtomicU32::new(0) }
+    }
+}
+
+/// What `BinaryDense` remembers from one step to the next.
+pub struct BinaryDenseState<const LEN: usize, const WORDS: usize> {
+    /// Inputs of the previous step, if there was one.
+    bitmask: Option<[u32; WORDS]>,
+    z: [i64; LEN],
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const WORDS: usize, A: Activation> Layer for BinaryDense<PREV_LEN, LEN, WORDS, A> {
+    type Input = [u32; WORDS];
+    type Output = [Fix; LEN];
+    type State = BinaryDenseState<LEN, WORDS>;
+
+    const INITIAL_STATE: Self::State = BinaryDenseState { bitmask: None, z: [0; LEN] };
+
+    fn forward(&self, bitmask: &[u32; WORDS], state: &mut Self::State) -> [Fix; LEN] {
+        match state.bitmask {
+            Some(previous) if delta_is_cheaper(&previous, bitmask) => {
+                binary_delta(&mut state.z, &previous, bitmask, &self.weights);
+            }
+
+            _ => {
+                state.z = self.biases.map(|b| b.to_bits() as i64);
+                binary_neurons(&mut state.z, bitmask, &self.weights);
+            }
+        }
+        state.bitmask = Some(*bitmask);
+
+        state.z.map(|z| self.activation.apply(narrow(z, &self.overflows)))
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::{neuron, xorshift, Id};
+    use core::array::from_fn;
+
+    const INPUTS: usize = 40;
+    const LEN: usize = 3;
+    const WORDS: usize = INPUTS.div_ceil(32);
+
+    fn bitmask(inputs: impl IntoIterator<Item = usize>) -> [u32; WORDS] {
+        let mut bitmask = [0; WORDS];
+        for i in inputs {
+            bitmask[i / 32] |= 1 << (i % 32);
+        }
+        bitmask
+    }
+
+    /// Checks that `BinaryDense` computes exactly the same pre-activations as
+    /// `neuron` over the same weights - for every single input alone (which,
+    /// as both just sum up i64s, covers every combination of them), all of
+    /// them at once and then inputs that change a few at a time, as
+    /// consecutive radar scans do, so that it goes through `binary_delta` too.
+    #[test]
+    fn binary_dense_matches_neuron() {
+        let mut seed = 0x2545_f491u32;
+        let mut random = || xorshift(&mut seed);
+
+        let rows: [[Fix; INPUTS]; LEN] = from_fn(|_| from_fn(|_| Fix::from_bits(random() as i32 >> 4)));
+        let biases: [Fix; LEN] = from_fn(|_| Fix::from_bits(random() as i32 >> 4));
+        let layer = BinaryDense::<INPUTS, LEN, WORDS, Id> {
+            weights: from_fn(|j| from_fn(|i| rows[i][j])),
+            biases,
+            activation: Id,
+            overflows: AtomicU32::new(0),
+        };
+
+        let mut changing = bitmask((0..INPUTS).filter(|i| i % 3 == 0));
+        let changing: Vec<_> = (0..100)
+            .map(|_| {
+                for _ in 0..random() % 4 {
+                    let i = random() as usize % INPUTS;
+                    changing[i / 32] ^= 1 << (i % 32);
+                }
+                changing
+            })
+            .collect();
+
+        let mut state = BinaryDense::<INPUTS, LEN, WORDS, Id>::INITIAL_STATE;
+        for bitmask in (0..INPUTS).map(|i| bitmask([i])).chain([bitmask(0..INPUTS)]).chain(changing) {
+            layer.forward(&bitmask, &mut state);
+
+            let inputs = <[Fix; INPUTS]>::from_bitmask(&bitmask);
+            let expected: Vec<_> = rows.iter().zip(biases).map(|(w, b)| neuron(&inputs, w, b)).collect();
+            assert_eq!(state.z.to_vec(), expected, "for {bitmask:?}");
+        }
+    }
+}
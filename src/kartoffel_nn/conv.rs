@@ -0,0 +1,168 @@
+use super::format::{self, Conv2dShape, Pool2dShape};
+use super::{narrow, read_fixes, read_matrix, seek_layer, Activation, Fix, Id, Layer, Num};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Pre-activation of output `(channel, y, x)` of a convolution, see
+/// `Num::Wide`; `weights` are laid out like in the model blob.
+pub fn conv2d_neuron<T: Num>(shape: &Conv2dShape, input: &[T], weights: &[T], bias: T, channel: usize, y: usize, x: usize) -> T::Wide {
+    let k = shape.kernel;
+    let mut z = bias.widen();
+
+    for ci in 0..shape.in_channels {
+        for ky in 0..k {
+            let Some(iy) = (y * shape.stride + ky).checked_sub(shape.padding).filter(|&iy| iy < shape.height) else {
+                continue;
+            };
+
+            for kx in 0..k {
+                let Some(ix) = (x * shape.stride + kx).checked_sub(shape.padding).filter(|&ix| ix < shape.width) else {
+                    continue;
+                };
+
+                let a = input[(ci * shape.height + iy) * shape.width + ix];
+                let w = weights[((channel * shape.in_channels + ci) * k + ky) * k + kx];
+                z = z + T::mul_wide(a, w);
+            }
+        }
+    }
+
+    z
+}
+
+/// Largest input within the window of output `(channel, y, x)` of a pooling
+/// layer.
+pub fn max_pool2d_neuron<T: Num>(shape: &Pool2dShape, input: &[T], channel: usize, y: usize, x: usize) -> T {
+    let cell = |py: usize, px: usize| input[(channel * shape.height + y * shape.size + py) * shape.width + x * shape.size + px];
+
+    let mut max = cell(0, 0);
+    for py in 0..shape.size {
+        for px in 0..shape.size {
+            if cell(py, px) > max {
+                max = cell(py, px);
+            }
+        }
+    }
+    max
+}
+
+/// 2D convolution over the grids at the beginning of the input, see
+/// `Conv2dShape`; the remaining inputs are passed through.
+pub struct Conv2d<const PREV_LEN: usize, const LEN: usize, const C_IN: usize, const C_OUT: usize, const K: usize, A> {
+    shape: Conv2dShape,
+    weights: [[[[Fix; K]; K]; C_IN]; C_OUT],
+    biases: [Fix; C_OUT],
+    activation: A,
+    overflows: AtomicU32,
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const C_IN: usize, const C_OUT: usize, const K: usize, A: Activation>
+    Conv2d<PREV_LEN, LEN, C_IN, C_OUT, K, A>
+{
+    #[cfg_attr(not(kartoffel_layer = "Conv2d"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_CONV2D, PREV_LEN, LEN);
+
+        let shape = Conv2dShape::read(&mut data);
+        assert!(shape.in_channels == C_IN, "model conv2d layer's input channels do not match its type");
+        assert!(shape.out_channels == C_OUT, "model conv2d layer's output channels do not match its type");
+        assert!(shape.kernel == K, "model conv2d layer's kernel size does not match its type");
+        assert!(shape.inputs() <= PREV_LEN, "model conv2d layer's grid is larger than its input");
+        assert!(LEN == shape.outputs() + (PREV_LEN - shape.inputs()), "model conv2d layer's shape does not match its output width");
+
+        let mut weights = [[[[Fix::ZERO; K]; K]; C_IN]; C_OUT];
+        let mut o = 0;
+        while o < C_OUT {
+            let mut i = 0;
+            while i < C_IN {
+                weights[o][i] = read_matrix(&mut data);
+                i += 1;
+            }
+            o += 1;
+        }
+
+        let biases = read_fixes(&mut data);
+
+        Conv2d {
+            shape,
+            weights,
+            biases,
+            activation: A::INSTANCE,
+            overflows: AtomicU32::new(0),
+        }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const C_IN: usize, const C_OUT: usize, const K: usize, A: Activation> Layer
+    for Conv2d<PREV_LEN, LEN, C_IN, C_OUT, K, A>
+{
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], _: &mut ()) -> [Fix; LEN] {
+        let weights = self.weights.as_flattened().as_flattened().as_flattened();
+        let (height, width) = (self.shape.out_height(), self.shape.out_width());
+
+        let mut next_activations = [Fix::ZERO; LEN];
+        for c in 0..C_OUT {
+            for y in 0..height {
+                for x in 0..width {
+                    let z = conv2d_neuron(&self.shape, activations, weights, self.biases[c], c, y, x);
+                    next_activations[(c * height + y) * width + x] = self.activation.apply(narrow(z, &self.overflows));
+                }
+            }
+        }
+
+        next_activations[self.shape.outputs()..].copy_from_slice(&activations[self.shape.inputs()..]);
+        next_activations
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+/// Max-pooling over the grids at the beginning of the input, see
+/// `Pool2dShape`; the remaining inputs are passed through.
+pub struct MaxPool2d<const PREV_LEN: usize, const LEN: usize> {
+    shape: Pool2dShape,
+}
+
+impl<const PREV_LEN: usize, const LEN: usize> MaxPool2d<PREV_LEN, LEN> {
+    #[cfg_attr(not(kartoffel_layer = "MaxPool2d"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<Id>(blob, index, format::KIND_MAX_POOL2D, PREV_LEN, LEN);
+
+        let shape = Pool2dShape::read(&mut data);
+        assert!(shape.inputs() <= PREV_LEN, "model pooling layer's grid is larger than its input");
+        assert!(LEN == shape.outputs() + (PREV_LEN - shape.inputs()), "model pooling layer's shape does not match its output width");
+
+        MaxPool2d { shape }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize> Layer for MaxPool2d<PREV_LEN, LEN> {
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], _: &mut ()) -> [Fix; LEN] {
+        let (height, width) = (self.shape.out_height(), self.shape.out_width());
+
+        let mut next_activations = [Fix::ZERO; LEN];
+        for c in 0..self.shape.channels {
+            for y in 0..height {
+                for x in 0..width {
+                    next_activations[(c * height + y) * width + x] = max_pool2d_neuron(&self.shape, activations, c, y, x);
+                }
+            }
+        }
+
+        next_activations[self.shape.outputs()..].copy_from_slice(&activations[self.shape.inputs()..]);
+        next_activations
+    }
+}
@@ -0,0 +1,52 @@
+use super::{format, narrow, neuron, read_fixes, read_matrix, seek_layer, Activation, Fix, Layer};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Recurrent layer: like `Dense`, but each neuron also sees the layer's own
+/// output from the previous step, which is kept in the layer's state.
+pub struct Elman<const PREV_LEN: usize, const LEN: usize, A> {
+    weights: [[Fix; PREV_LEN]; LEN],
+    recurrent: [[Fix; LEN]; LEN],
+    biases: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> Elman<PREV_LEN, LEN, A> {
+    #[cfg_attr(not(kartoffel_layer = "Elman"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_ELMAN, PREV_LEN, LEN);
+        let weights = read_matrix(&mut data);
+        let recurrent = read_matrix(&mut data);
+        let biases = read_fixes(&mut data);
+
+        Elman {
+            weights,
+            recurrent,
+            biases,
+            activation: A::INSTANCE,
+            overflows: AtomicU32::new(0),
+        }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, A: Activation> Layer for Elman<PREV_LEN, LEN, A> {
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = [Fix; LEN];
+
+    const INITIAL_STATE: [Fix; LEN] = [Fix::ZERO; LEN];
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], hidden: &mut [Fix; LEN]) -> [Fix; LEN] {
+        let mut next_activations = [Fix::ZERO; LEN];
+        for (i, a) in next_activations.iter_mut().enumerate() {
+            let z = neuron(activations, &self.weights[i], self.biases[i]) + neuron(hidden, &self.recurrent[i], Fix::ZERO);
+            *a = self.activation.apply(narrow(z, &self.overflows));
+        }
+        *hidden = next_activations;
+        next_activations
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
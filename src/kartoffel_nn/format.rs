@@ -0,0 +1,400 @@
+//! Model blob layout (all integers little-endian):
+//!
+//! ```text
+//! magic       b"KNN\0"
+//! version     u16
+//! int_bits    u8     \ Q-format of every `Fix` weight and bias,
+//! frac_bits   u8     / must match `Fix`
+//! layer_count u16
+//! schema      u32    `shape::SCHEMA_HASH` of the observations the model was
+//!                    trained on
+//! layer_count times:
+//!     kind        u8     see `KIND_*`
+//!     inputs      u16
+//!     outputs     u16
+//!     activation  u8     see `ACTIVATION_*`
+//!     activation_param i32   e.g. `LeakyRelu`'s slope as `Fix` bits, zero for
+//!                            activations without parameters
+//! layer_count times, depending on kind:
+//!     KIND_DENSE:
+//!         weights     outputs * inputs i32 bit patterns, row per neuron
+//!         biases      outputs i32 bit patterns
+//!     KIND_QDENSE:
+//!         input_frac  u8     inputs are quantized to i8 with this many
+//!                            fractional bits
+//!         weights     outputs * inputs i8, row per neuron
+//!         multipliers outputs i32  \ turn a neuron's i32 accumulator into
+//!         shifts      outputs u8   / `Fix` bits: (acc * mul) >> shift
+//!         biases      outputs i32 bit patterns
+//!     KIND_ELMAN:
+//!         weights     outputs * inputs i32 bit patterns, row per neuron
+//!         recurrent   outputs * outputs i32 bit patterns, row per neuron
+//!         biases      outputs i32 bit patterns
+//!     KIND_CONV2D:
+//!         shape       see `Conv2dShape`
+//!         weights     out_channels * in_channels * kernel * kernel i32 bit
+//!                     patterns
+//!         biases      out_channels i32 bit patterns
+//!     KIND_MAX_POOL2D:
+//!         shape       see `Pool2dShape`
+//!     KIND_SPARSE:
+//!         nnz         u16    number of non-zero weights
+//!         row_ends    outputs u16, end of each neuron's weights
+//!         columns     nnz u16, input of each weight
+//!         values      nnz i32 bit patterns
+//!         biases      outputs i32 bit patterns
+//!     KIND_HEAD:
+//!         role        u8     see `HEAD_*`
+//!     KIND_EXPERT:
+//!         (nothing)
+//!     KIND_RESIDUAL:
+//!         layers      u8     number of layers in the block
+//!     KIND_LAYER_NORM:
+//!         epsilon     i32 bit pattern, added to the variance
+//!         gamma       outputs i32 bit patterns
+//!         beta        outputs i32 bit patterns
+//!     KIND_BATCH_NORM:
+//!         channels    u16    outputs of a dense layer, out_channels of a
+//!                            convolution
+//!         epsilon     i32 bit pattern, added to the variance
+//!         gamma       channels i32 bit patterns
+//!         beta        channels i32 bit patterns
+//!         mean        channels i32 bit patterns, running mean
+//!         var         channels i32 bit patterns, running variance
+//! ```
+//!
+//! `KIND_HEAD` isn't a layer, but marks the beginning of one of the model's
+//! heads: the layers before the first one form a trunk, whose output each head
+//! takes as its input; a head's inputs and outputs must both be the trunk's
+//! width. Models without heads are a policy head on their own.
+//!
+//! Similarly, `KIND_EXPERT` marks the beginning of one of the model's experts:
+//! the layers before the first one form a gate, with an output per expert;
+//! each expert takes the model's input (so an expert's inputs and outputs must
+//! both be its width) and produces action logits, which get blended by the
+//! softmax of the gate's output. Models can't have both heads and experts.
+//!
+//! `KIND_RESIDUAL` starts a block of the next `layers` layers, whose input gets
+//! added to its output, so the block's inputs and outputs must both be the
+//! marker's width; blocks can nest, but must stay within a head or an expert.
+//!
+//! `KIND_BATCH_NORM` never reaches the robot - `build.rs` folds it into the
+//! dense or convolutional layer before it, which must then have no
+//! activation; the batch norm's activation applies instead.
+//!
+//! Spatial layers see their inputs as `in_channels` grids stored row by row,
+//! one after another, followed by extra features (e.g. the arm-ready bit),
+//! which they pass through untouched.
+//!
+//! A PyTorch `nn.RNN` layer maps to `KIND_ELMAN` as `weight_ih_l0`,
+//! `weight_hh_l0` and `bias_ih_l0 + bias_hh_l0`.
+//!
+//! Shared with `build.rs`, so nothing in here may depend on `fixed`.
+
+pub const MAGIC: [u8; 4] = *b"KNN\0";
+pub const VERSION: u16 = 4;
+pub const HEADER_LEN: usize = 14;
+
+/// Version 3 blobs are version 4 ones without `schema`, as all of them were
+/// trained on the observations of the 7x7 radar that's one for void and bots,
+/// followed by the arm-ready bit - which hash to this.
+#[allow(dead_code)]
+pub const V3_SCHEMA_HASH: u32 = 0x1607_f45e;
+pub const LAYER_DESC_LEN: usize = 10;
+
+pub const KIND_DENSE: u8 = 0;
+pub const KIND_QDENSE: u8 = 1;
+pub const KIND_ELMAN: u8 = 2;
+pub const KIND_CONV2D: u8 = 3;
+pub const KIND_MAX_POOL2D: u8 = 4;
+pub const KIND_SPARSE: u8 = 5;
+pub const KIND_HEAD: u8 = 6;
+pub const KIND_EXPERT: u8 = 7;
+pub const KIND_RESIDUAL: u8 = 8;
+pub const KIND_LAYER_NORM: u8 = 9;
+pub const KIND_BATCH_NORM: u8 = 10;
+
+pub const ACTIVATION_ID: u8 = 0;
+pub const ACTIVATION_RELU: u8 = 1;
+pub const ACTIVATION_LEAKY_RELU: u8 = 2;
+pub const ACTIVATION_HARD_TANH: u8 = 3;
+pub const ACTIVATION_HARD_SIGMOID: u8 = 4;
+pub const ACTIVATION_TANH: u8 = 5;
+pub const ACTIVATION_SIGMOID: u8 = 6;
+
+// Only build.rs tells the heads apart, the robot takes them in order
+
+/// Logits of the robot's actions.
+#[allow(dead_code)]
+pub const HEAD_POLICY: u8 = 0;
+/// A single estimate of how good the robot's situation is.
+#[allow(dead_code)]
+pub const HEAD_VALUE: u8 = 1;
+/// A single logit of there being an enemy nearby.
+#[allow(dead_code)]
+pub const HEAD_ENEMY_NEARBY: u8 = 2;
+
+/// Version 4 header standing in for a version 3 blob's, see
+/// `V3_SCHEMA_HASH`, to be put instead of its first `HEADER_LEN - 4` bytes;
+/// `None` for blobs that aren't version 3.
+// Only build.rs reads version 3 blobs
+#[allow(dead_code)]
+pub fn upgrade_v3(blob: &[u8]) -> Option<[u8; HEADER_LEN]> {
+    let v3_header = blob.get(..HEADER_LEN - 4)?;
+    if v3_header[..4] != MAGIC || v3_header[4..6] != 3u16.to_le_bytes() {
+        return None;
+    }
+
+    let mut header = [0; HEADER_LEN];
+    header[..HEADER_LEN - 4].copy_from_slice(v3_header);
+    header[4..6].copy_from_slice(&VERSION.to_le_bytes());
+    header[HEADER_LEN - 4..].copy_from_slice(&V3_SCHEMA_HASH.to_le_bytes());
+    Some(header)
+}
+
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub const fn new(bytes: &'a [u8], pos: usize) -> Self {
+        Reader { bytes, pos }
+    }
+
+    pub const fn u8(&mut self) -> u8 {
+        assert!(self.pos < self.bytes.len(), "model blob is truncated");
+        let b = self.bytes[self.pos];
+        self.pos += 1;
+        b
+    }
+
+    pub const fn i8(&mut self) -> i8 {
+        self.u8() as i8
+    }
+
+    pub const fn u16(&mut self) -> u16 {
+        u16::from_le_bytes([self.u8(), self.u8()])
+    }
+
+    pub const fn i32(&mut self) -> i32 {
+        i32::from_le_bytes([self.u8(), self.u8(), self.u8(), self.u8()])
+    }
+
+    pub const fn u32(&mut self) -> u32 {
+        self.i32() as u32
+    }
+}
+
+pub struct Header {
+    pub int_bits: u8,
+    pub frac_bits: u8,
+    pub layer_count: usize,
+    /// Checked by build.rs, which knows the robot's observations.
+    #[allow(dead_code)]
+    pub schema: u32,
+}
+
+impl Header {
+    pub const fn read(blob: &[u8]) -> Self {
+        let mut r = Reader::new(blob, 0);
+        let magic = [r.u8(), r.u8(), r.u8(), r.u8()];
+        assert!(matches!(magic, MAGIC), "model blob has a bad magic");
+        assert!(r.u16() == VERSION, "model blob has an unsupported version");
+
+        Header {
+            int_bits: r.u8(),
+            frac_bits: r.u8(),
+            layer_count: r.u16() as usize,
+            schema: r.u32(),
+        }
+    }
+
+    pub const fn data_offset(&self) -> usize {
+        HEADER_LEN + self.layer_count * LAYER_DESC_LEN
+    }
+}
+
+pub struct LayerDesc {
+    pub kind: u8,
+    pub inputs: usize,
+    pub outputs: usize,
+    pub activation: u8,
+    pub activation_param: i32,
+}
+
+impl LayerDesc {
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut r = Reader::new(blob, HEADER_LEN + index * LAYER_DESC_LEN);
+
+        LayerDesc {
+            kind: r.u8(),
+            inputs: r.u16() as usize,
+            outputs: r.u16() as usize,
+            activation: r.u8(),
+            activation_param: r.i32(),
+        }
+    }
+
+    /// Length of the layer's data, which starts at `offset`.
+    pub const fn data_len(&self, blob: &[u8], offset: usize) -> usize {
+        match self.kind {
+            KIND_DENSE => (self.outputs * self.inputs + self.outputs) * 4,
+            KIND_QDENSE => 1 + self.outputs * self.inputs + self.outputs * (4 + 1 + 4),
+            KIND_ELMAN => (self.outputs * self.inputs + self.outputs * self.outputs + self.outputs) * 4,
+
+            KIND_CONV2D => {
+                let shape = Conv2dShape::read(&mut Reader::new(blob, offset));
+                Conv2dShape::LEN + (shape.weights() + shape.out_channels) * 4
+            }
+
+            KIND_MAX_POOL2D => Pool2dShape::LEN,
+
+            KIND_SPARSE => {
+                let nnz = Reader::new(blob, offset).u16() as usize;
+                2 + self.outputs * 2 + nnz * (2 + 4) + self.outputs * 4
+            }
+
+            KIND_HEAD => 1,
+            KIND_EXPERT => 0,
+            KIND_RESIDUAL => 1,
+            KIND_LAYER_NORM => 4 + self.outputs * 2 * 4,
+
+            KIND_BATCH_NORM => {
+                let channels = Reader::new(blob, offset).u16() as usize;
+                2 + 4 + channels * 4 * 4
+            }
+
+            _ => panic!("model uses an unknown layer kind"),
+        }
+    }
+}
+
+/// `height`, `width`, `in_channels`, `out_channels`, `kernel`, `stride` and
+/// `padding`, one u8 each; padding is zero-padding on every side.
+#[derive(Clone, Copy)]
+pub struct Conv2dShape {
+    pub height: usize,
+    pub width: usize,
+    pub in_channels: usize,
+    pub out_channels: usize,
+    pub kernel: usize,
+    pub stride: usize,
+    pub padding: usize,
+}
+
+impl Conv2dShape {
+    pub const LEN: usize = 7;
+
+    pub const fn read(r: &mut Reader) -> Self {
+        let shape = Conv2dShape {
+            height: r.u8() as usize,
+            width: r.u8() as usize,
+            in_channels: r.u8() as usize,
+            out_channels: r.u8() as usize,
+            kernel: r.u8() as usize,
+            stride: r.u8() as usize,
+            padding: r.u8() as usize,
+        };
+
+        assert!(shape.kernel > 0 && shape.stride > 0, "model conv2d layer has a zero kernel or stride");
+        assert!(
+            shape.kernel <= shape.height + 2 * shape.padding && shape.kernel <= shape.width + 2 * shape.padding,
+            "model conv2d layer's kernel is larger than its input"
+        );
+        shape
+    }
+
+    pub const fn out_height(&self) -> usize {
+        (self.height + 2 * self.padding - self.kernel) / self.stride + 1
+    }
+
+    pub const fn out_width(&self) -> usize {
+        (self.width + 2 * self.padding - self.kernel) / self.stride + 1
+    }
+
+    /// Number of inputs the grids take, without the extra features.
+    pub const fn inputs(&self) -> usize {
+        self.in_channels * self.height * self.width
+    }
+
+    /// Number of outputs the grids produce, without the extra features.
+    pub const fn outputs(&self) -> usize {
+        self.out_channels * self.out_height() * self.out_width()
+    }
+
+    pub const fn weights(&self) -> usize {
+        self.out_channels * self.in_channels * self.kernel * self.kernel
+    }
+}
+
+/// `height`, `width`, `channels` and `size`, one u8 each; pools over
+/// non-overlapping `size` x `size` windows, dropping rows and columns that
+/// don't fill a whole window.
+#[derive(Clone, Copy)]
+pub struct Pool2dShape {
+    pub height: usize,
+    pub width: usize,
+    pub channels: usize,
+    pub size: usize,
+}
+
+impl Pool2dShape {
+    pub const LEN: usize = 4;
+
+    pub const fn read(r: &mut Reader) -> Self {
+        let shape = Pool2dShape {
+            height: r.u8() as usize,
+            width: r.u8() as usize,
+            channels: r.u8() as usize,
+            size: r.u8() as usize,
+        };
+
+        assert!(shape.size > 0, "model pooling layer has a zero size");
+        shape
+    }
+
+    pub const fn out_height(&self) -> usize {
+        self.height / self.size
+    }
+
+    pub const fn out_width(&self) -> usize {
+        self.width / self.size
+    }
+
+    pub const fn inputs(&self) -> usize {
+        self.channels * self.height * self.width
+    }
+
+    pub const fn outputs(&self) -> usize {
+        self.channels * self.out_height() * self.out_width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v3_blobs_upgrade_to_v4() {
+        let desc = [KIND_DENSE, 1, 0, 2, 0, ACTIVATION_LEAKY_RELU, 0x40, 0, 0, 0];
+        let mut blob: Vec<u8> = [&MAGIC[..], &3u16.to_le_bytes(), &[10, 22], &1u16.to_le_bytes(), &desc].concat();
+        // two weights and two biases
+        blob.extend([0x11; 4 * 4]);
+
+        let header = upgrade_v3(&blob).unwrap();
+        blob.splice(..HEADER_LEN - 4, header);
+
+        let header = Header::read(&blob);
+        assert_eq!((header.int_bits, header.frac_bits, header.layer_count), (10, 22, 1));
+        assert_eq!(header.schema, V3_SCHEMA_HASH);
+
+        let desc = LayerDesc::read(&blob, 0);
+        assert_eq!((desc.kind, desc.inputs, desc.outputs), (KIND_DENSE, 1, 2));
+        assert_eq!((desc.activation, desc.activation_param), (ACTIVATION_LEAKY_RELU, 0x40));
+        assert_eq!(header.data_offset() + desc.data_len(&blob, header.data_offset()), blob.len());
+
+        // which is now a version 4 one
+        assert!(upgrade_v3(&blob).is_none());
+    }
+}
@@ -0,0 +1,147 @@
+use super::{Fix, Layer};
+
+/// Model inputs starting with `channels` square grids of radar cells, stored
+/// row by row, which can be mirrored left to right.
+pub trait MirrorInput {
+    fn mirror(&self, side: usize, channels: usize) -> Self;
+}
+
+impl<const WORDS: usize> MirrorInput for [u32; WORDS] {
+    fn mirror(&self, side: usize, channels: usize) -> Self {
+        let mut mirrored = *self;
+        for y in 0..side * channels {
+            for x in 0..side {
+                let (from, to) = (y * side + x, y * side + side - 1 - x);
+                let bit = self[from / 32] >> (from % 32) & 1;
+                mirrored[to / 32] = mirrored[to / 32] & !(1 << (to % 32)) | bit << (to % 32);
+            }
+        }
+        mirrored
+    }
+}
+
+impl<const LEN: usize> MirrorInput for [Fix; LEN] {
+    fn mirror(&self, side: usize, channels: usize) -> Self {
+        let mut mirrored = *self;
+        for y in 0..side * channels {
+            for x in 0..side {
+                mirrored[y * side + side - 1 - x] = self[y * side + x];
+            }
+        }
+        mirrored
+    }
+}
+
+/// Model outputs that can be averaged with the ones for the mirrored input.
+pub trait MirrorOutput {
+    /// Averages `self` with `mirrored`, whose outputs `left` and `right` get
+    /// swapped back first (where there are such outputs, i.e. for the
+    /// policy).
+    fn average_mirrored(&self, mirrored: &Self, left: usize, right: usize) -> Self;
+}
+
+impl<const LEN: usize> MirrorOutput for [Fix; LEN] {
+    fn average_mirrored(&self, mirrored: &Self, left: usize, right: usize) -> Self {
+        let swap = left < LEN && right < LEN;
+
+        core::array::from_fn(|i| {
+            let j = match i {
+                i if swap && i == left => right,
+                i if swap && i == right => left,
+                i => i,
+            };
+
+            // in i64, so that it can't overflow
+            Fix::from_bits(((self[i].to_bits() as i64 + mirrored[j].to_bits() as i64) >> 1) as i32)
+        })
+    }
+}
+
+impl<A: MirrorOutput, B: MirrorOutput> MirrorOutput for (A, B) {
+    fn average_mirrored(&self, mirrored: &Self, left: usize, right: usize) -> Self {
+        (self.0.average_mirrored(&mirrored.0, left, right), self.1.average_mirrored(&mirrored.1, left, right))
+    }
+}
+
+/// Runs `L` on the observation and on its mirror image, left and right
+/// swapped, then averages both, with the latter's turn-left (`LEFT`) and
+/// turn-right (`RIGHT`) logits swapped back - so that the robot handles
+/// mirrored situations the same way, whether or not the model learnt that.
+///
+/// Both runs keep a state of their own, so this costs twice the model.
+pub struct Mirrored<L, const SIDE: usize, const CHANNELS: usize, const LEFT: usize, const RIGHT: usize>(pub L);
+
+impl<L, const SIDE: usize, const CHANNELS: usize, const LEFT: usize, const RIGHT: usize> Layer
+    for Mirrored<L, SIDE, CHANNELS, LEFT, RIGHT>
+where
+    L: Layer,
+    L::Input: MirrorInput,
+    L::Output: MirrorOutput,
+{
+    type Input = L::Input;
+    type Output = L::Output;
+    type State = (L::State, L::State);
+
+    const INITIAL_STATE: Self::State = (L::INITIAL_STATE, L::INITIAL_STATE);
+
+    fn forward(&self, input: &L::Input, state: &mut Self::State) -> L::Output {
+        let output = self.0.forward(input, &mut state.0);
+        let mirrored = self.0.forward(&input.mirror(SIDE, CHANNELS), &mut state.1);
+        output.average_mirrored(&mirrored, LEFT, RIGHT)
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        self.0.overflows(f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::{xorshift, BinaryInput, Dense, Id};
+    use core::array::from_fn;
+    use core::sync::atomic::AtomicU32;
+
+    const SIDE: usize = 3;
+    const CHANNELS: usize = 2;
+    /// Both grids, followed by a flag that isn't mirrored.
+    const INPUTS: usize = SIDE * SIDE * CHANNELS + 1;
+    const LEFT: usize = 2;
+    const RIGHT: usize = 3;
+
+    #[test]
+    fn bitmask_mirrors_like_fixes() {
+        let mut seed = 0x27d4_eb2fu32;
+        for _ in 0..100 {
+            let bitmask = [xorshift(&mut seed) & ((1 << INPUTS) - 1)];
+
+            let mirrored = <[Fix; INPUTS]>::from_bitmask(&bitmask.mirror(SIDE, CHANNELS));
+            assert_eq!(mirrored, <[Fix; INPUTS]>::from_bitmask(&bitmask).mirror(SIDE, CHANNELS), "for {bitmask:?}");
+        }
+    }
+
+    /// Checks that `Mirrored` makes even a model that isn't symmetric pick the
+    /// mirror image of its action for the mirror image of its input - exactly,
+    /// as both average the same two outputs.
+    #[test]
+    fn mirrored_is_symmetric() {
+        let mut seed = 0x1656_67b1u32;
+        let mut random = || xorshift(&mut seed);
+
+        let model = Mirrored::<_, SIDE, CHANNELS, LEFT, RIGHT>(Dense::<INPUTS, 6, Id> {
+            weights: from_fn(|_| from_fn(|_| Fix::from_bits(random() as i32 >> 8))),
+            biases: from_fn(|_| Fix::from_bits(random() as i32 >> 8)),
+            activation: Id,
+            overflows: AtomicU32::new(0),
+        });
+
+        for _ in 0..100 {
+            let input: [Fix; INPUTS] = from_fn(|_| if random() % 2 == 0 { Fix::ZERO } else { Fix::ONE });
+
+            let output = model.forward(&input, &mut ((), ()));
+            let mut mirrored = model.forward(&input.mirror(SIDE, CHANNELS), &mut ((), ()));
+            mirrored.swap(LEFT, RIGHT);
+            assert_eq!(output, mirrored, "for {input:?}");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use super::{narrow, softmax, Fix, Layer, Num};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Adds an expert's `logits`, weighted by the gate's `weight` for that expert,
+/// to the pre-blended logits `z`.
+pub fn mix<T: Num>(z: &mut [T::Wide], weight: T, logits: &[T]) {
+    for (z, &x) in z.iter_mut().zip(logits) {
+        *z = *z + T::mul_wide(x, weight);
+    }
+}
+
+/// Outputs of the experts, as forked by `Fork<E0, Fork<E1, ...>>`, that can be
+/// blended into `LEN` logits.
+pub trait Blend<const LEN: usize> {
+    /// `mix`es every expert's logits into `z`, `weights` being the gate's
+    /// weights of the experts, in order.
+    fn blend(&self, weights: &[Fix], z: &mut [i64; LEN]);
+}
+
+impl<const LEN: usize> Blend<LEN> for [Fix; LEN] {
+    fn blend(&self, weights: &[Fix], z: &mut [i64; LEN]) {
+        mix(z, weights[0], self);
+    }
+}
+
+impl<const LEN: usize, T: Blend<LEN>> Blend<LEN> for ([Fix; LEN], T) {
+    fn blend(&self, weights: &[Fix], z: &mut [i64; LEN]) {
+        mix(z, weights[0], &self.0);
+        self.1.blend(&weights[1..], z);
+    }
+}
+
+/// Mixture of `EXPERTS` experts, whose logits get blended by the softmax of
+/// the gate's output - lets specialist behaviours be trained separately.
+pub struct Mixture<G, E, const EXPERTS: usize, const LEN: usize> {
+    gate: G,
+    experts: E,
+    overflows: AtomicU32,
+}
+
+impl<G, E, const EXPERTS: usize, const LEN: usize> Mixture<G, E, EXPERTS, LEN> {
+    #[cfg_attr(not(kartoffel_layer = "Mixture"), allow(dead_code))]
+    pub const fn new(gate: G, experts: E) -> Self {
+        Mixture { gate, experts, overflows: AtomicU32::new(0) }
+    }
+}
+
+impl<G, E, const EXPERTS: usize, const LEN: usize> Layer for Mixture<G, E, EXPERTS, LEN>
+where
+    G: Layer<Output = [Fix; EXPERTS]>,
+    E: Layer<Input = G::Input>,
+    E::Output: Blend<LEN>,
+{
+    type Input = G::Input;
+    type Output = [Fix; LEN];
+    type State = (G::State, E::State);
+
+    const INITIAL_STATE: Self::State = (G::INITIAL_STATE, E::INITIAL_STATE);
+
+    fn forward(&self, input: &G::Input, state: &mut Self::State) -> [Fix; LEN] {
+        let weights = softmax(&self.gate.forward(input, &mut state.0));
+
+        let mut z = [0; LEN];
+        self.experts.forward(input, &mut state.1).blend(&weights, &mut z);
+        z.map(|z| narrow(z, &self.overflows))
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        self.gate.overflows(f);
+        self.experts.overflows(f);
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::{xorshift, Fork, SOFTMAX_MAX_ERROR};
+    use core::array::from_fn;
+
+    /// Outputs the same whatever its input.
+    struct Constant<const LEN: usize>([Fix; LEN]);
+
+    impl<const LEN: usize> Layer for Constant<LEN> {
+        type Input = ();
+        type Output = [Fix; LEN];
+        type State = ();
+
+        const INITIAL_STATE: () = ();
+
+        fn forward(&self, _: &(), _: &mut ()) -> [Fix; LEN] {
+            self.0
+        }
+    }
+
+    /// Checks that blending the experts in `Fix` ends up close to doing it in
+    /// f64, given the same gate and expert outputs - what's left is the
+    /// softmax's error times the experts' logits, plus roundings.
+    #[test]
+    fn mixture_matches_f64() {
+        let mut seed = 0x85eb_ca6bu32;
+        let mut random = || Fix::from_bits(xorshift(&mut seed) as i32 >> 5);
+
+        for _ in 0..1000 {
+            let gate: [Fix; 3] = from_fn(|_| random());
+            let experts: [[Fix; 6]; 3] = from_fn(|_| from_fn(|_| random()));
+
+            let mixture: Mixture<_, _, 3, 6> = Mixture::new(
+                Constant(gate),
+                Fork(Constant(experts[0]), Fork(Constant(experts[1]), Constant(experts[2]))),
+            );
+            let blended = mixture.forward(&(), &mut ((), ((), ((), ()))));
+
+            let max = gate.iter().map(|x| x.to_num::<f64>()).fold(f64::MIN, f64::max);
+            let weights = gate.map(|x| (x.to_num::<f64>() - max).exp());
+            let sum: f64 = weights.iter().sum();
+
+            for (action, x) in blended.iter().enumerate() {
+                let logits = experts.map(|logits| logits[action].to_num::<f64>());
+                let reference: f64 = logits.iter().zip(weights).map(|(x, w)| x * w / sum).sum();
+                let max_error = logits.iter().map(|x| x.abs()).sum::<f64>() * SOFTMAX_MAX_ERROR + 4.0 * Fix::DELTA.to_num::<f64>();
+
+                let error = (x.to_num::<f64>() - reference).abs();
+                assert!(
+                    error <= max_error,
+                    "Mixture blends {blended:?} instead of {reference} for action {action}, which is off by {error}, more than {max_error}",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::{format, narrow, read_fixes, seek_layer, Activation, Fix, Layer, Num};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Mean and standard deviation of `x`, with `epsilon` added to the variance;
+/// `x` mustn't span more than `Fix` can hold.
+pub fn moments<T: Num>(x: &[T], epsilon: T, overflows: &AtomicU32) -> (T, T) {
+    let n = x.len() as i32;
+
+    let sum = x.iter().fold(T::ZERO.widen(), |sum, &x| sum + x.widen());
+    let mean = T::narrow(T::div_wide(sum, n), overflows);
+
+    let squares = x.iter().fold(T::ZERO.widen(), |sum, &x| sum + T::mul_wide(x - mean, x - mean));
+    let std = T::sqrt_wide(T::div_wide(squares, n) + epsilon.widen());
+
+    (mean, std)
+}
+
+/// Pre-activation of a single layer-normalized `x`; the normalized value is at
+/// most sqrt(n) away from zero, so only `gamma` can make it overflow.
+pub fn layer_norm_neuron<T: Num>(x: T, (mean, std): (T, T), gamma: T, beta: T) -> T::Wide {
+    beta.widen() + T::mul_wide((x - mean) / std, gamma)
+}
+
+/// Folds a batch norm into the layer before it, whose `weights` are a chunk per
+/// channel (a neuron's row for dense layers, an output channel's kernels for
+/// convolutions) and `biases` one per channel; `scales` are `gamma / sqrt(var +
+/// epsilon)`, which takes std, so the caller works them out.
+// Only build.rs folds batch norms
+#[allow(dead_code)]
+pub fn fold_batch_norm(weights: &mut [f64], biases: &mut [f64], scales: &[f64], mean: &[f64], beta: &[f64]) {
+    let chunk = weights.len() / scales.len();
+    for (c, (weights, bias)) in weights.chunks_mut(chunk).zip(biases).enumerate() {
+        for w in weights {
+            *w *= scales[c];
+        }
+        *bias = (*bias - mean[c]) * scales[c] + beta[c];
+    }
+}
+
+/// Normalizes its inputs to zero mean and unit variance, then scales them by
+/// `gamma` and shifts them by `beta`, input by input.
+pub struct LayerNorm<const LEN: usize, A> {
+    epsilon: Fix,
+    gamma: [Fix; LEN],
+    beta: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
+}
+
+impl<const LEN: usize, A: Activation> LayerNorm<LEN, A> {
+    #[cfg_attr(not(kartoffel_layer = "LayerNorm"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_LAYER_NORM, LEN, LEN);
+
+        let epsilon = Fix::from_bits(data.i32());
+        assert!(epsilon.to_bits() > 0, "model layer norm's epsilon is not positive");
+
+        let gamma = read_fixes(&mut data);
+        let beta = read_fixes(&mut data);
+
+        LayerNorm { epsilon, gamma, beta, activation: A::INSTANCE, overflows: AtomicU32::new(0) }
+    }
+}
+
+impl<const LEN: usize, A: Activation> Layer for LayerNorm<LEN, A> {
+    type Input = [Fix; LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, x: &[Fix; LEN], _: &mut ()) -> [Fix; LEN] {
+        let moments = moments(x, self.epsilon, &self.overflows);
+
+        core::array::from_fn(|i| {
+            let z = layer_norm_neuron(x[i], moments, self.gamma[i], self.beta[i]);
+            self.activation.apply(narrow(z, &self.overflows))
+        })
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::xorshift;
+
+    /// Checks that a dense layer with a batch norm folded into it computes
+    /// what the batch norm would out of the layer's outputs.
+    #[test]
+    fn folding_batch_norm_keeps_outputs() {
+        const INPUTS: usize = 5;
+        const CHANNELS: usize = 3;
+
+        let mut seed = 0xc2b2_ae35u32;
+        let mut random = || xorshift(&mut seed) as f64 / u32::MAX as f64 * 2.0 - 1.0;
+
+        let weights: Vec<f64> = (0..INPUTS * CHANNELS).map(|_| random()).collect();
+        let biases: Vec<f64> = (0..CHANNELS).map(|_| random()).collect();
+        let epsilon = 1e-5;
+        let gamma: Vec<f64> = (0..CHANNELS).map(|_| random()).collect();
+        let beta: Vec<f64> = (0..CHANNELS).map(|_| random()).collect();
+        let mean: Vec<f64> = (0..CHANNELS).map(|_| random()).collect();
+        let var: Vec<f64> = (0..CHANNELS).map(|_| random() + 1.0).collect();
+
+        let scales: Vec<f64> = (0..CHANNELS).map(|c| gamma[c] / (var[c] + epsilon).sqrt()).collect();
+        let (mut folded_weights, mut folded_biases) = (weights.clone(), biases.clone());
+        fold_batch_norm(&mut folded_weights, &mut folded_biases, &scales, &mean, &beta);
+
+        let dense = |weights: &[f64], biases: &[f64], x: &[f64]| -> Vec<f64> {
+            weights.chunks(INPUTS).zip(biases).map(|(w, b)| b + w.iter().zip(x).map(|(w, x)| w * x).sum::<f64>()).collect()
+        };
+
+        for _ in 0..100 {
+            let x: Vec<f64> = (0..INPUTS).map(|_| random()).collect();
+
+            let expected = dense(&weights, &biases, &x);
+            let expected = (0..CHANNELS).map(|c| (expected[c] - mean[c]) / (var[c] + epsilon).sqrt() * gamma[c] + beta[c]);
+            for (actual, expected) in dense(&folded_weights, &folded_biases, &x).into_iter().zip(expected) {
+                assert!((actual - expected).abs() < 1e-9, "folded layer gives {actual} instead of {expected} for {x:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::{activation, narrow, narrow_with, softmax_into, Fix, Overflow};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::sync::atomic::AtomicU32;
+
+/// Numbers the layers' kernels and activations can run on - `Fix` on the
+/// robot, plus `f64` in `build.rs`, which runs the same weights in both to
+/// see how much precision `Fix` loses (through its own copy of the layers,
+/// as the layer types themselves only run on `Fix`).
+pub trait Num:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// What neurons sum their inputs up in before `narrow`ing them back.
+    type Wide: Copy + Add<Output = Self::Wide>;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_fix(x: Fix) -> Self;
+
+    // The ones allowed to be dead are only there for build.rs
+    #[allow(dead_code)]
+    fn to_fix(self) -> Fix;
+    #[allow(dead_code)]
+    fn to_f64(self) -> f64;
+
+    fn widen(self) -> Self::Wide;
+
+    /// `a * w` as a `Wide`, rounded the same way as `Self`'s multiplication.
+    fn mul_wide(a: Self, w: Self) -> Self::Wide;
+
+    /// Brings a neuron's sum back into `Self`, counting the times it didn't
+    /// fit.
+    fn narrow(z: Self::Wide, overflows: &AtomicU32) -> Self;
+
+    /// `narrow`, but saturating whatever `OVERFLOW` is, see `narrow_with`.
+    #[allow(dead_code)]
+    fn saturate(z: Self::Wide, overflows: &AtomicU32) -> Self;
+
+    fn div_wide(z: Self::Wide, d: i32) -> Self::Wide;
+
+    /// Square root of a non-negative `z`, which must fit into `Self`.
+    fn sqrt_wide(z: Self::Wide) -> Self;
+
+    fn div_int(self, d: i32) -> Self;
+    fn tanh(self) -> Self;
+
+    /// Probabilities of the classes scored by `logits`, see `softmax`.
+    #[allow(dead_code)]
+    fn softmax(logits: &[Self], probabilities: &mut [Self]);
+}
+
+impl Num for Fix {
+    /// `Fix` bits with 32 more integer bits, e.g. I42F22.
+    type Wide = i64;
+
+    const ZERO: Self = Fix::ZERO;
+    const ONE: Self = Fix::ONE;
+
+    fn from_fix(x: Fix) -> Self {
+        x
+    }
+
+    fn to_fix(self) -> Fix {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_num()
+    }
+
+    fn widen(self) -> i64 {
+        self.to_bits() as i64
+    }
+
+    fn mul_wide(a: Self, w: Self) -> i64 {
+        (a.to_bits() as i64 * w.to_bits() as i64) >> Fix::FRAC_NBITS
+    }
+
+    fn narrow(z: i64, overflows: &AtomicU32) -> Self {
+        narrow(z, overflows)
+    }
+
+    fn saturate(z: i64, overflows: &AtomicU32) -> Self {
+        narrow_with(Overflow::Saturate, z, overflows)
+    }
+
+    fn div_wide(z: i64, d: i32) -> i64 {
+        z / d as i64
+    }
+
+    fn sqrt_wide(z: i64) -> Self {
+        let bits = ((z.max(0) as u128) << Fix::FRAC_NBITS).isqrt();
+        Fix::from_bits(bits.min(i32::MAX as u128) as i32)
+    }
+
+    fn div_int(self, d: i32) -> Self {
+        self / d
+    }
+
+    fn tanh(self) -> Self {
+        activation::tanh(self)
+    }
+
+    fn softmax(logits: &[Self], probabilities: &mut [Self]) {
+        softmax_into(logits, probabilities)
+    }
+}
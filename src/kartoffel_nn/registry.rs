@@ -0,0 +1,28 @@
+/// Several models embedded side by side under their own names (e.g. one for
+/// exploring, one for fighting and one for escaping), each with a session of
+/// its own; `build.rs` generates one for the models it got.
+///
+/// All of them take the same observations, packed into a bitmask the way
+/// `BinaryInput` expects, and produce the same kind of output.
+pub trait Registry {
+    type Output;
+
+    /// Models' names, in the order of their indices.
+    const NAMES: &'static [&'static str];
+
+    /// Number of inputs all of the models take.
+    const INPUTS: usize;
+
+    fn forward(&mut self, index: usize, bitmask: &[u32]) -> Self::Output;
+
+    /// Makes model `index` forget everything it has seen so far.
+    fn reset(&mut self, index: usize);
+
+    /// Calls `f` with the number of overflown pre-activations of each layer of
+    /// each model, in order.
+    fn overflows(&self, f: &mut dyn FnMut(u32));
+
+    fn index(name: &str) -> Option<usize> {
+        Self::NAMES.iter().position(|&n| n == name)
+    }
+}
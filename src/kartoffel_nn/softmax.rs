@@ -0,0 +1,100 @@
+use super::activation::{const_exp, const_from_f64};
+use super::Fix;
+
+// The tests check `softmax` against this
+#[cfg_attr(not(test), allow(dead_code))]
+pub const SOFTMAX_MAX_ERROR: f64 = 1e-3;
+
+/// exp(-k) for k = 0..EXP_INT_LUT_LEN; anything smaller rounds to zero in
+/// `Fix` anyway.
+const EXP_INT_LUT: [Fix; EXP_INT_LUT_LEN] = exp_int_lut();
+const EXP_INT_LUT_LEN: usize = 16;
+
+/// exp(-f) for f = 0, 1/2^EXP_FRAC_LUT_STEP_BITS, ..., 1.
+const EXP_FRAC_LUT: [Fix; EXP_FRAC_LUT_LEN] = exp_frac_lut();
+const EXP_FRAC_LUT_STEP_BITS: u32 = 5;
+const EXP_FRAC_LUT_LEN: usize = (1 << EXP_FRAC_LUT_STEP_BITS) + 1;
+
+/// Probabilities of the classes scored by `logits`.
+pub fn softmax<const N: usize>(logits: &[Fix; N]) -> [Fix; N] {
+    let mut probabilities = [Fix::ZERO; N];
+    softmax_into(logits, &mut probabilities);
+    probabilities
+}
+
+/// `softmax` for slices, writing the probabilities into `probabilities`.
+pub fn softmax_into(logits: &[Fix], probabilities: &mut [Fix]) {
+    let Some(max) = logits.iter().max().copied() else {
+        return;
+    };
+
+    for (p, &x) in probabilities.iter_mut().zip(logits) {
+        *p = exp_neg(max.wrapping_sub(x));
+    }
+
+    // At least one of the exps is 1, so this can't be zero
+    let sum = probabilities.iter().fold(Fix::ZERO, |sum, &e| sum.saturating_add(e));
+
+    for p in probabilities {
+        *p /= sum;
+    }
+}
+
+/// exp(-x) for a non-negative `x`.
+fn exp_neg(x: Fix) -> Fix {
+    let bits = x.to_bits() as u32;
+    let k = (bits >> Fix::FRAC_NBITS) as usize;
+    if k >= EXP_INT_LUT_LEN {
+        return Fix::ZERO;
+    }
+
+    let frac = bits & ((1 << Fix::FRAC_NBITS) - 1);
+    let shift = Fix::FRAC_NBITS - EXP_FRAC_LUT_STEP_BITS;
+    let i = (frac >> shift) as usize;
+    let t = Fix::from_bits(((frac & ((1 << shift) - 1)) << EXP_FRAC_LUT_STEP_BITS) as i32);
+    let exp_frac = EXP_FRAC_LUT[i] + (EXP_FRAC_LUT[i + 1] - EXP_FRAC_LUT[i]) * t;
+
+    EXP_INT_LUT[k] * exp_frac
+}
+
+const fn exp_int_lut() -> [Fix; EXP_INT_LUT_LEN] {
+    let mut lut = [Fix::ZERO; EXP_INT_LUT_LEN];
+    let mut k = 0;
+    while k < EXP_INT_LUT_LEN {
+        lut[k] = const_from_f64(const_exp(-(k as f64)));
+        k += 1;
+    }
+    lut
+}
+
+const fn exp_frac_lut() -> [Fix; EXP_FRAC_LUT_LEN] {
+    let mut lut = [Fix::ZERO; EXP_FRAC_LUT_LEN];
+    let mut i = 0;
+    while i < EXP_FRAC_LUT_LEN {
+        lut[i] = const_from_f64(const_exp(-(i as f64) / (1 << EXP_FRAC_LUT_STEP_BITS) as f64));
+        i += 1;
+    }
+    lut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::xorshift;
+
+    #[test]
+    fn softmax_matches_f64() {
+        let mut seed = 0x9e3779b9u32;
+        for _ in 0..10_000 {
+            let logits: [Fix; 6] = [(); 6].map(|_| Fix::from_bits(xorshift(&mut seed) as i32 >> 5));
+
+            let max = logits.iter().map(|x| x.to_num::<f64>()).fold(f64::MIN, f64::max);
+            let sum: f64 = logits.iter().map(|x| (x.to_num::<f64>() - max).exp()).sum();
+
+            for (logit, p) in logits.iter().zip(softmax(&logits)) {
+                let error = (p.to_num::<f64>() - (logit.to_num::<f64>() - max).exp() / sum).abs();
+                assert!(error <= SOFTMAX_MAX_ERROR, "softmax({logits:?}) is off by {error}, which is more than {SOFTMAX_MAX_ERROR}");
+            }
+        }
+    }
+}
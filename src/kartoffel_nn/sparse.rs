@@ -0,0 +1,84 @@
+use super::{format, narrow, read_fixes, seek_layer, Activation, Fix, Layer, Num};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Pre-activation of a single sparse neuron; same as `neuron` over the weights
+/// that aren't zero.
+pub fn sparse_neuron<T: Num>(activations: &[T], columns: &[u16], values: &[T], bias: T) -> T::Wide {
+    columns
+        .iter()
+        .zip(values)
+        .fold(bias.widen(), |z, (&c, &w)| z + T::mul_wide(activations[c as usize], w))
+}
+
+/// `Dense` that only stores its `NNZ` non-zero weights, row by row (CSR).
+pub struct Sparse<const PREV_LEN: usize, const LEN: usize, const NNZ: usize, A> {
+    /// Neuron `i`'s weights are at `row_ends[i - 1]..row_ends[i]`.
+    row_ends: [u16; LEN],
+    columns: [u16; NNZ],
+    values: [Fix; NNZ],
+    biases: [Fix; LEN],
+    activation: A,
+    overflows: AtomicU32,
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const NNZ: usize, A: Activation> Sparse<PREV_LEN, LEN, NNZ, A> {
+    #[cfg_attr(not(kartoffel_layer = "Sparse"), allow(dead_code))]
+    pub const fn read(blob: &[u8], index: usize) -> Self {
+        let mut data = seek_layer::<A>(blob, index, format::KIND_SPARSE, PREV_LEN, LEN);
+        assert!(data.u16() as usize == NNZ, "model sparse layer's weight count does not match its type");
+
+        let mut row_ends = [0; LEN];
+        let mut i = 0;
+        while i < LEN {
+            row_ends[i] = data.u16();
+            assert!(row_ends[i] as usize <= NNZ, "model sparse layer's row is out of range");
+            assert!(i == 0 || row_ends[i] >= row_ends[i - 1], "model sparse layer's rows are out of order");
+            i += 1;
+        }
+        assert!(LEN == 0 || row_ends[LEN - 1] as usize == NNZ, "model sparse layer's rows do not cover its weights");
+
+        let mut columns = [0; NNZ];
+        let mut i = 0;
+        while i < NNZ {
+            columns[i] = data.u16();
+            assert!((columns[i] as usize) < PREV_LEN, "model sparse layer's column is out of range");
+            i += 1;
+        }
+
+        let values = read_fixes(&mut data);
+        let biases = read_fixes(&mut data);
+
+        Sparse {
+            row_ends,
+            columns,
+            values,
+            biases,
+            activation: A::INSTANCE,
+            overflows: AtomicU32::new(0),
+        }
+    }
+}
+
+impl<const PREV_LEN: usize, const LEN: usize, const NNZ: usize, A: Activation> Layer for Sparse<PREV_LEN, LEN, NNZ, A> {
+    type Input = [Fix; PREV_LEN];
+    type Output = [Fix; LEN];
+    type State = ();
+
+    const INITIAL_STATE: () = ();
+
+    fn forward(&self, activations: &[Fix; PREV_LEN], _: &mut ()) -> [Fix; LEN] {
+        let mut next_activations = [Fix::ZERO; LEN];
+        let mut start = 0;
+        for (i, a) in next_activations.iter_mut().enumerate() {
+            let end = self.row_ends[i] as usize;
+            let z = sparse_neuron(activations, &self.columns[start..end], &self.values[start..end], self.biases[i]);
+            *a = self.activation.apply(narrow(z, &self.overflows));
+            start = end;
+        }
+        next_activations
+    }
+
+    fn overflows(&self, f: &mut dyn FnMut(u32)) {
+        f(self.overflows.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,82 @@
+use super::Fix;
+
+/// How an unrolled neuron adds up one of its inputs, `a` being the inputs' bits
+/// as i64s.
+#[derive(Clone, Copy)]
+pub enum Term {
+    /// `(a[input] * weight) >> FRAC_NBITS`
+    Mul { input: usize, weight: i32 },
+
+    /// `±a[input] >> shift`, for weights of `±2^(FRAC_NBITS - shift)`
+    Shr { input: usize, negate: bool, shift: u32 },
+
+    /// `±a[input] << shift`, for weights of `±2^(FRAC_NBITS + shift)`
+    Shl { input: usize, negate: bool, shift: u32 },
+}
+
+impl Term {
+    /// `None` for zero weights, which unrolled neurons skip.
+    pub fn new(input: usize, weight: Fix) -> Option<Self> {
+        let bits = weight.to_bits();
+        if bits == 0 {
+            return None;
+        }
+        if !bits.unsigned_abs().is_power_of_two() {
+            return Some(Term::Mul { input, weight: bits });
+        }
+
+        let negate = bits < 0;
+        let log = bits.unsigned_abs().trailing_zeros();
+
+        Some(if log <= Fix::FRAC_NBITS {
+            Term::Shr { input, negate, shift: Fix::FRAC_NBITS - log }
+        } else {
+            Term::Shl { input, negate, shift: log - Fix::FRAC_NBITS }
+        })
+    }
+
+    /// What the code `build.rs` generates for the term computes.
+    pub fn eval(&self, a: &[i64]) -> i64 {
+        let signed = |input: usize, negate: bool| if negate { -a[input] } else { a[input] };
+
+        match *self {
+            Term::Mul { input, weight } => (a[input] * weight as i64) >> Fix::FRAC_NBITS,
+            Term::Shr { input, negate, shift } => signed(input, negate) >> shift,
+            Term::Shl { input, negate, shift } => signed(input, negate) << shift,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::{neuron, xorshift};
+
+    /// Checks that unrolled neurons compute the same pre-activations as
+    /// `neuron`, zero and power-of-two weights (both below and above one)
+    /// included, on inputs all over `Fix`'s range.
+    #[test]
+    fn unrolled_neurons_match_neuron() {
+        let mut seed = 0x1b87_3593u32;
+        let mut random = || xorshift(&mut seed);
+
+        for _ in 0..1000 {
+            let weights: Vec<_> = (0..16)
+                .map(|_| match random() % 4 {
+                    0 => Fix::ZERO,
+                    1 => Fix::from_bits(1 << (random() % (Fix::INT_NBITS + Fix::FRAC_NBITS - 2))),
+                    2 => -Fix::from_bits(1 << (random() % (Fix::INT_NBITS + Fix::FRAC_NBITS - 2))),
+                    _ => Fix::from_bits(random() as i32),
+                })
+                .collect();
+            let bias = Fix::from_bits(random() as i32);
+            let input: Vec<_> = (0..weights.len()).map(|_| Fix::from_bits(random() as i32)).collect();
+
+            let a: Vec<_> = input.iter().map(|x| x.to_bits() as i64).collect();
+            let terms = weights.iter().enumerate().filter_map(|(j, &w)| Term::new(j, w));
+            let unrolled = bias.to_bits() as i64 + terms.map(|term| term.eval(&a)).sum::<i64>();
+
+            assert_eq!(unrolled, neuron(&input, &weights, bias), "for weights {weights:?} and inputs {input:?}");
+        }
+    }
+}
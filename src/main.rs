@@ -1,25 +1,96 @@
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 
 mod kartoffel_nn;
+mod model;
+// The rest of the schema is there for build.rs
+#[allow(dead_code)]
+mod shape;
+mod view;
 
 use kartoffel::*;
-use kartoffel_nn::{KARTOFFEL_NN, Fix};
+// rather than std's, which tests have in scope
+use kartoffel::{print, println};
+use kartoffel_nn::{softmax, xorshift, Fix, Registry};
+use model::Models;
+use shape::{Tile, ACTIONS, OBSERVATIONS, OBSERVATION_WORDS, RADAR_SIZE, TURN_LEFT, TURN_RIGHT};
+use view::View;
 
-const N: usize = 7;
+/// When the most likely move is less likely than this, the robot samples its
+/// move from the network's distribution instead; zero means it always goes
+/// for the most likely one.
+const SAMPLING_THRESHOLD: Fix = Fix::ZERO;
+
+/// When the model has a value head that rates the situation below this, the
+/// robot samples its move too, to try to get out of it, and takes a full-size
+/// scan next, in case its stale view is what got it there; value heads rate
+/// bad situations below zero, so that's what it defaults to - `Fix::MIN`
+/// means it never does.
+const EXPLORATION_VALUE: Fix = Fix::ZERO;
+
+/// For how many steps after an enemy got next to it the robot keeps escaping.
+const ESCAPE_STEPS: u32 = 8;
+
+/// At most how many steps the robot goes without a full-size scan, as the
+/// rest of its view grows stale in the meantime.
+const MAX_VIEW_AGE: u32 = 4;
 
 fn argmax<T: Ord>(xs: &[T]) -> Option<usize> {
     xs.iter().enumerate().max_by_key(|(_, t)| *t).map(|(i, _)| i)
 }
 
-struct Robot {
+/// Radar scans the robot can take, by their size.
+trait Scan {
+    fn scan() -> Self;
+}
+
+impl Scan for RadarScan<3> {
+    fn scan() -> Self {
+        radar_scan_3x3()
+    }
+}
+
+impl Scan for RadarScan<5> {
+    fn scan() -> Self {
+        radar_scan_5x5()
+    }
+}
+
+impl Scan for RadarScan<7> {
+    fn scan() -> Self {
+        radar_scan_7x7()
+    }
 }
 
-fn print_scan<const N:usize>(scan: &RadarScan<N>) {
+impl Scan for RadarScan<9> {
+    fn scan() -> Self {
+        radar_scan_9x9()
+    }
+}
+
+/// Robot seeing `N`x`N` tiles around it, which has to be the `RADAR_SIZE`
+/// its models take.
+struct Robot<const N: usize> {
+    nn: Models,
+    view: View<N>,
+    /// Steps since the robot last took a full-size scan.
+    since_full_scan: u32,
+    /// Which way the robot faces, see `HEADING` - as of the compass, followed
+    /// through the robot's turns in between; `None` until the compass tells.
+    heading: Option<u8>,
+    /// Index of the model that handled the previous step.
+    model: usize,
+    /// Steps since an enemy was last next to the robot.
+    since_threat: u32,
+    overflows: u32,
+    rng: u32,
+}
+
+fn print_scan<const N:usize>(view: &View<N>) {
     let n = N as i8;
     for y in -n/2..=n/2 {
         for x in -n/2..=n/2 {
-            let c = scan.at(x, y);
+            let c = view.at(x, y);
             print!("{c}");
         }
         println!("");
@@ -27,26 +98,28 @@ fn print_scan<const N:usize>(scan: &RadarScan<N>) {
     println!("");
 }
 
-impl Robot {
-    fn get_observations(&self, scan: &RadarScan<N>) -> [Fix; 50] {
-        let mut observations = [Fix::ZERO; 50];
-
-        let n = threat_map::N as i8;
-        for i in 0..49 {
-            let x = i%n - n/2;
-            let y = i/n - n/2;
-            let index = i as usize;
-
-            let c = scan.at(x, y);
-            if c == ' ' || c == '@' {
-                observations[index] = Fix::ONE;
-            }
-        }
+impl<const N: usize> Robot<N>
+where
+    RadarScan<N>: Scan,
+{
+    /// Fails the build when the scans, the observations and the models don't
+    /// line up - checked in `new`.
+    const SHAPES_MATCH: () = {
+        assert!(N == RADAR_SIZE, "the robot scans a different number of tiles than its observations have");
+        assert!(Models::INPUTS == OBSERVATIONS, "the models take a different number of observations than the robot makes");
+    };
 
-        observations[observations.len() - 1] = if is_arm_ready() { Fix::ZERO } else { Fix::ONE };
+    fn get_observations(&self) -> [u32; OBSERVATION_WORDS] {
+        let mut observations = [0; OBSERVATION_WORDS];
+        shape::encode(
+            |x, y| Tile::from_char(self.view.at(x, y)),
+            !is_arm_ready(),
+            self.heading,
+            |index| observations[index / 32] |= 1 << (index % 32),
+        );
 
-        // for (i, o) in observations.iter().enumerate() {
-        //     print!("{o} ");
+        // for i in 0..OBSERVATIONS {
+        //     print!("{} ", observations[i / 32] >> (i % 32) & 1);
         //     if i % N == N - 1 {
         //         println!("");
         //     }
@@ -56,22 +129,152 @@ impl Robot {
         observations
     }
 
-    fn step(&mut self) {    
+    /// Whether there's an enemy in `view` and whether there's one right next
+    /// to the robot.
+    fn enemies(view: &View<N>) -> (bool, bool) {
+        let n = N as i8;
+        let mut enemy_visible = false;
+        let mut enemy_adjacent = false;
+        for y in -n/2..=n/2 {
+            for x in -n/2..=n/2 {
+                if (x, y) != (0, 0) && Tile::from_char(view.at(x, y)) == Tile::Bot {
+                    enemy_visible = true;
+                    enemy_adjacent |= x.abs() + y.abs() == 1;
+                }
+            }
+        }
+        (enemy_visible, enemy_adjacent)
+    }
+
+    /// Radar scheduling - picks the size of the scan to take, going by what
+    /// the robot saw so far: a cheap 3x3 one when there's an enemy right next
+    /// to it, a 5x5 one when there's one further away and a full-size one
+    /// when exploring or when its view has grown too stale (or the model's
+    /// value head rated its last situation low, see `EXPLORATION_VALUE`).
+    fn pick_scan(&self) -> usize {
+        let (enemy_visible, enemy_adjacent) = Self::enemies(&self.view);
+
+        let size = if self.since_full_scan >= MAX_VIEW_AGE {
+            N
+        } else if enemy_adjacent {
+            3
+        } else if enemy_visible {
+            5
+        } else {
+            N
+        };
+
+        size.min(N)
+    }
+
+    /// Takes a scan of `size`, merging it into the robot's view.
+    fn scan(&mut self, size: usize) {
         radar_wait();
-        let scan = radar_scan_7x7();
-        // print_scan(&scan);
-        let observations = self.get_observations(&scan);
-        let nn_output = KARTOFFEL_NN.forward(observations);
+        match size {
+            3 => self.view.merge(&radar_scan_3x3()),
+            5 => self.view.merge(&radar_scan_5x5()),
+            _ => self.view.merge(&RadarScan::<N>::scan()),
+        }
+
+        self.since_full_scan = if size == N { 0 } else { self.since_full_scan.saturating_add(1) };
+    }
+
+    /// Gating policy - picks the model that handles the situation, falling
+    /// back to the first one when there's no model for it.
+    ///
+    /// The game doesn't tell the robot when it got hurt, so an enemy getting
+    /// next to it stands in for that.
+    fn pick_model(&mut self) -> usize {
+        let (enemy_visible, enemy_adjacent) = Self::enemies(&self.view);
+
+        self.since_threat = if enemy_adjacent { 0 } else { self.since_threat.saturating_add(1) };
+
+        let name = if enemy_visible && is_arm_ready() {
+            "combat"
+        } else if enemy_visible || self.since_threat < ESCAPE_STEPS {
+            "escape"
+        } else {
+            "exploration"
+        };
+
+        Models::index(name).unwrap_or(0)
+    }
+
+    /// Follows the robot stepping forward (`dy = -1`) or backward (`dy = 1`)
+    /// with its view - as long as the tile it's stepping onto is floor, as
+    /// otherwise it can't tell whether the move worked out, in which case the
+    /// next scan is a full-size one.
+    fn follow_step(&mut self, dy: i8) {
+        if Tile::from_char(self.view.at(0, dy)) == Tile::Floor {
+            self.view.step(dy);
+        } else {
+            self.since_full_scan = MAX_VIEW_AGE;
+        }
+    }
+
+    fn step(&mut self) {    
+        self.scan(self.pick_scan());
+        if cfg!(feature = "compass") {
+            self.read_compass();
+        }
+        // print_scan(&self.view);
+        let observations = self.get_observations();
+
+        let model = self.pick_model();
+        if model != self.model {
+            // whatever the model remembers is from before the switch
+            self.nn.reset(model);
+            self.model = model;
+        }
+
+        let heads = self.nn.forward(model, &observations);
+        let nn_output: [Fix; ACTIONS] = heads.policy;
+        self.report_overflows();
         let nn_move = argmax(&nn_output).expect("nn output is empty");
+        let exploring = heads.value.is_some_and(|value| value < EXPLORATION_VALUE);
+        if exploring {
+            self.since_full_scan = MAX_VIEW_AGE;
+        }
+        // probabilities are never below zero, so with the default threshold
+        // there's no need to compute them unless exploring
+        let nn_move = if exploring || SAMPLING_THRESHOLD > Fix::ZERO {
+            let probabilities = softmax(&nn_output);
+            // for p in probabilities {
+            //     print!("{p:.2} ");
+            // }
+            // println!("");
+            if exploring || probabilities[nn_move] < SAMPLING_THRESHOLD {
+                self.sample(&probabilities)
+            } else {
+                nn_move
+            }
+        } else {
+            nn_move
+        };
         // println!("nn move: {nn_move}");
         match nn_move {
             nn_move@0..=3 => {
                 motor_wait();
+                // the view follows the robot
                 match nn_move {
-                    0 => motor_step_fw(),
-                    1 => motor_step_bw(),
-                    2 => motor_turn_left(),
-                    3 => motor_turn_right(),
+                    0 => {
+                        motor_step_fw();
+                        self.follow_step(-1);
+                    }
+                    1 => {
+                        motor_step_bw();
+                        self.follow_step(1);
+                    }
+                    TURN_LEFT => {
+                        motor_turn_left();
+                        self.view.turn_left();
+                        self.heading = self.heading.map(|heading| (heading + 3) % 4);
+                    }
+                    TURN_RIGHT => {
+                        motor_turn_right();
+                        self.view.turn_right();
+                        self.heading = self.heading.map(|heading| (heading + 1) % 4);
+                    }
                     _ => unreachable!()
                 };
             }
@@ -84,14 +287,50 @@ impl Robot {
         }
     }
 
+    fn read_compass(&mut self) {
+        if let Some(direction) = compass_dir() {
+            self.heading = Some(match direction {
+                Direction::N => 0,
+                Direction::E => 1,
+                Direction::S => 2,
+                Direction::W => 3,
+            });
+        }
+    }
+
+    fn sample(&mut self, probabilities: &[Fix]) -> usize {
+        let mut r = Fix::from_bits((xorshift(&mut self.rng) >> (32 - Fix::FRAC_NBITS)) as i32);
+        for (i, &p) in probabilities.iter().enumerate() {
+            if r < p {
+                return i;
+            }
+            r -= p;
+        }
+        probabilities.len() - 1
+    }
+
+    fn report_overflows(&mut self) {
+        let mut overflows = 0;
+        self.nn.overflows(&mut |n| overflows += n);
+        if overflows == self.overflows {
+            return;
+        }
+        self.overflows = overflows;
+
+        print!("overflows:");
+        self.nn.overflows(&mut |n| print!(" {n}"));
+        println!("");
+    }
+
     fn new() -> Self {
-        Robot{ }
+        let () = Self::SHAPES_MATCH;
+        Robot{ nn: Models::new(), view: View::new(), since_full_scan: u32::MAX, heading: None, model: 0, since_threat: u32::MAX, overflows: 0, rng: timer_seed() | 1 }
     }
 }
 
-#[no_mangle]
+#[cfg_attr(not(test), no_mangle)]
 fn main() {
-    let mut robot = Robot::new();
+    let mut robot = Robot::<RADAR_SIZE>::new();
     loop {
         robot.step();
     }
@@ -0,0 +1,24 @@
+// Defines a module for each of the embedded models, with `KartoffelNN` (the
+// model's type, derived from its layers), the `KARTOFFEL_NN` static, `heads`
+// (which picks the model's output apart) and, with `KARTOFFEL_UNROLL=1`, the
+// unrolled layers, plus `Models`, the registry of all of them - see `build.rs`
+
+use crate::kartoffel_nn::*;
+use crate::shape::ACTIONS;
+
+/// What the model's heads make of an observation; models without a value or
+/// an enemy-nearby head leave those out.
+pub struct Heads {
+    /// Logits of the robot's actions.
+    pub policy: [Fix; ACTIONS],
+
+    /// How good the robot's situation is.
+    pub value: Option<Fix>,
+
+    /// Logit of there being an enemy nearby - an auxiliary task that only
+    /// helps the trunk during training, nothing uses it yet.
+    #[allow(dead_code)]
+    pub enemy_nearby: Option<Fix>,
+}
+
+include!(concat!(env!("OUT_DIR"), "/kartoffel_nn_model.rs"));
@@ -0,0 +1,377 @@
+// Shared with `build.rs`, which rejects models that don't fit these.
+
+/// What the radar sees on a tile.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Floor,
+    Wall,
+    Void,
+    Bot,
+    /// Anything else the radar draws.
+    Unknown,
+}
+
+impl Tile {
+    pub fn from_char(c: char) -> Self {
+        match c {
+            '.' => Tile::Floor,
+            '-' | '|' | '+' => Tile::Wall,
+            ' ' => Tile::Void,
+            '@' => Tile::Bot,
+            _ => Tile::Unknown,
+        }
+    }
+
+    /// Channel the tile sets its cell in, if any.
+    pub fn channel(self) -> Option<usize> {
+        CHANNELS.iter().position(|tiles| tiles.contains(&self))
+    }
+}
+
+/// Tiles each of the radar channels is one for - void and bots, both of which
+/// the robot should keep off, unless the `one-hot-tiles` feature gives every
+/// kind of tile a channel of its own, so that the model can tell a drop from
+/// an enemy it could stab.
+#[cfg(not(feature = "one-hot-tiles"))]
+pub const CHANNELS: &[&[Tile]] = &[&[Tile::Void, Tile::Bot]];
+
+#[cfg(feature = "one-hot-tiles")]
+pub const CHANNELS: &[&[Tile]] = &[&[Tile::Floor], &[Tile::Wall], &[Tile::Void], &[Tile::Bot], &[Tile::Unknown]];
+
+pub const RADAR_CHANNELS: usize = CHANNELS.len();
+
+/// Side of the radar grids the observations start with - 7 unless one of the
+/// `radar-*` features picks another; the robot faces their top row, so
+/// mirroring them left to right swaps its left and right.
+#[cfg(not(any(feature = "radar-3x3", feature = "radar-5x5", feature = "radar-9x9")))]
+pub const RADAR_SIZE: usize = 7;
+
+#[cfg(feature = "radar-3x3")]
+pub const RADAR_SIZE: usize = 3;
+
+#[cfg(feature = "radar-5x5")]
+pub const RADAR_SIZE: usize = 5;
+
+#[cfg(feature = "radar-9x9")]
+pub const RADAR_SIZE: usize = 9;
+
+#[cfg(any(
+    all(feature = "radar-3x3", feature = "radar-5x5"),
+    all(feature = "radar-3x3", feature = "radar-9x9"),
+    all(feature = "radar-5x5", feature = "radar-9x9"),
+))]
+compile_error!("features `radar-3x3`, `radar-5x5` and `radar-9x9` are mutually exclusive");
+
+/// How a feature's values come about - all of them are zeros and ones, which
+/// the robot packs into a bitmask, so none of them need normalizing.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// One radar grid per channel, channel after channel and row after row
+    /// (i.e. with `RADAR_SIZE` being 7, cell `(x, y)` of channel `c` is at
+    /// `(c * 7 + y) * 7 + x`, the way convolution layers take them), a cell
+    /// being one when its tile is one of the channel's `CHANNELS`.
+    Radar,
+    /// One when something holds.
+    Flag,
+    /// One of `len` values, one-hot - or all of them zero, when it's unknown.
+    OneHot,
+    /// Same as `Radar`, but turned by the robot's heading so that the top row
+    /// is the one furthest north.
+    NorthUpRadar,
+}
+
+/// What a feature observes - `encode` has a way of encoding each of these.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The tiles around the robot.
+    Radar,
+    /// Whether the arm is still cooling down.
+    ArmCoolingDown,
+    /// Which way the robot faces, as of its compass.
+    Heading,
+}
+
+/// A named range of the observations.
+#[derive(Clone, Copy)]
+pub struct Feature {
+    pub name: &'static str,
+    pub offset: usize,
+    pub len: usize,
+    pub source: Source,
+    pub encoding: Encoding,
+}
+
+impl Feature {
+    pub const fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// Index of the feature's `i`-th value in the observations.
+    pub const fn at(&self, i: usize) -> usize {
+        assert!(i < self.len);
+        self.offset + i
+    }
+
+    /// Whether `encode` can encode the feature's source that way.
+    const fn is_encodable(&self) -> bool {
+        match self.source {
+            Source::Radar => {
+                matches!(self.encoding, Encoding::Radar | Encoding::NorthUpRadar) && self.len == RADAR_CHANNELS * RADAR_SIZE * RADAR_SIZE
+            }
+            Source::ArmCoolingDown => matches!(self.encoding, Encoding::Flag) && self.len == 1,
+            Source::Heading => matches!(self.encoding, Encoding::OneHot) && self.len == 4,
+        }
+    }
+}
+
+pub const RADAR: Feature = Feature {
+    name: "radar",
+    offset: 0,
+    len: RADAR_CHANNELS * RADAR_SIZE * RADAR_SIZE,
+    source: Source::Radar,
+    encoding: RADAR_ENCODING,
+};
+
+#[cfg(not(feature = "allocentric"))]
+const RADAR_ENCODING: Encoding = Encoding::Radar;
+
+#[cfg(feature = "allocentric")]
+const RADAR_ENCODING: Encoding = Encoding::NorthUpRadar;
+
+pub const ARM_COOLING_DOWN: Feature = Feature {
+    name: "arm_cooling_down",
+    offset: RADAR.end(),
+    len: 1,
+    source: Source::ArmCoolingDown,
+    encoding: Encoding::Flag,
+};
+
+/// Which way the robot faces, with the `compass` feature - north, east, south
+/// or west.
+pub const HEADING: Feature = Feature {
+    name: "heading",
+    offset: ARM_COOLING_DOWN.end(),
+    len: 4,
+    source: Source::Heading,
+    encoding: Encoding::OneHot,
+};
+
+/// Features the observations are made of, one after another - models get
+/// trained on this very layout, see `SCHEMA_HASH`.
+#[cfg(not(feature = "compass"))]
+pub const SCHEMA: &[Feature] = &[RADAR, ARM_COOLING_DOWN];
+
+#[cfg(feature = "compass")]
+pub const SCHEMA: &[Feature] = &[RADAR, ARM_COOLING_DOWN, HEADING];
+
+pub const OBSERVATIONS: usize = SCHEMA[SCHEMA.len() - 1].end();
+
+const _: () = {
+    let mut i = 0;
+    while i < SCHEMA.len() {
+        assert!(SCHEMA[i].is_encodable(), "SCHEMA has a feature that `encode` can't encode");
+        i += 1;
+    }
+};
+
+/// Coordinates in the robot's frame of the tile `(x, y)` away from it in the
+/// world's, north being `y < 0`, when it's facing `heading` (see `HEADING`).
+pub fn from_world(x: i8, y: i8, heading: u8) -> (i8, i8) {
+    (0..heading).fold((x, y), |(x, y), _| (y, -x))
+}
+
+/// Encodes what the robot senses the way `SCHEMA` lays it out, calling `set`
+/// with the index of every observation that's one (the rest being zero) -
+/// `tile` gives the tile `(x, y)` away from the robot in its frame, as with
+/// `RadarScan`, and `heading` is `None` until the compass tells.
+///
+/// The robot packs the observations into a bitmask this way, while `build.rs`
+/// makes up synthetic ones with it.
+pub fn encode(tile: impl Fn(i8, i8) -> Tile, arm_cooling_down: bool, heading: Option<u8>, mut set: impl FnMut(usize)) {
+    let r = (RADAR_SIZE / 2) as i8;
+
+    for feature in SCHEMA {
+        match feature.source {
+            Source::Radar => {
+                // until the compass tells, north is wherever the robot faces
+                let heading = match feature.encoding {
+                    Encoding::NorthUpRadar => heading.unwrap_or(0),
+                    _ => 0,
+                };
+
+                for y in -r..=r {
+                    for x in -r..=r {
+                        let (tx, ty) = from_world(x, y, heading);
+                        if let Some(channel) = tile(tx, ty).channel() {
+                            let cell = (y + r) as usize * RADAR_SIZE + (x + r) as usize;
+                            set(feature.at(channel * RADAR_SIZE * RADAR_SIZE + cell));
+                        }
+                    }
+                }
+            }
+
+            Source::ArmCoolingDown => {
+                if arm_cooling_down {
+                    set(feature.at(0));
+                }
+            }
+
+            Source::Heading => {
+                if let Some(heading) = heading {
+                    set(feature.at(heading as usize));
+                }
+            }
+        }
+    }
+}
+
+/// What `encode` was given, as far as the observations tell - tiles in none of
+/// the channels (e.g. floor and walls, by default) all look the same.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Decoded {
+    /// Channel of the tile `(x, y)` away from the robot in its frame, at
+    /// `[y + RADAR_SIZE / 2][x + RADAR_SIZE / 2]`.
+    pub radar: [[Option<usize>; RADAR_SIZE]; RADAR_SIZE],
+    pub arm_cooling_down: bool,
+    /// `None` when `SCHEMA` has no heading, too.
+    pub heading: Option<u8>,
+}
+
+/// Goes the other way around from `encode`, `SCHEMA` feature by feature,
+/// `is_set` telling whether the observation at an index is one - for checking
+/// `encode`, or what a trainer encodes, against.
+pub fn decode(is_set: impl Fn(usize) -> bool) -> Decoded {
+    let r = (RADAR_SIZE / 2) as i8;
+    let mut decoded = Decoded { radar: [[None; RADAR_SIZE]; RADAR_SIZE], arm_cooling_down: false, heading: None };
+
+    // radar grids facing north need the heading first
+    for feature in SCHEMA.iter().filter(|feature| feature.source == Source::Heading) {
+        decoded.heading = (0..feature.len).find(|&i| is_set(feature.at(i))).map(|i| i as u8);
+    }
+
+    for feature in SCHEMA {
+        match feature.source {
+            Source::Radar => {
+                let heading = match feature.encoding {
+                    Encoding::NorthUpRadar => decoded.heading.unwrap_or(0),
+                    _ => 0,
+                };
+
+                for channel in 0..RADAR_CHANNELS {
+                    for y in -r..=r {
+                        for x in -r..=r {
+                            let cell = (y + r) as usize * RADAR_SIZE + (x + r) as usize;
+                            if is_set(feature.at(channel * RADAR_SIZE * RADAR_SIZE + cell)) {
+                                let (tx, ty) = from_world(x, y, heading);
+                                decoded.radar[(ty + r) as usize][(tx + r) as usize] = Some(channel);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Source::ArmCoolingDown => decoded.arm_cooling_down = is_set(feature.at(0)),
+            Source::Heading => (),
+        }
+    }
+
+    decoded
+}
+
+/// FNV-1a hash of `SCHEMA`, along with the radar's size and `CHANNELS`, which
+/// models carry so that `build.rs` can reject the ones trained on a different
+/// layout.
+pub const SCHEMA_HASH: u32 = schema_hash();
+
+const fn schema_hash() -> u32 {
+    const fn hash(mut h: u32, bytes: &[u8]) -> u32 {
+        let mut i = 0;
+        while i < bytes.len() {
+            h = (h ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+            i += 1;
+        }
+        h
+    }
+
+    let mut h = hash(0x811c_9dc5, &(RADAR_SIZE as u32).to_le_bytes());
+
+    let mut i = 0;
+    while i < CHANNELS.len() {
+        let mut j = 0;
+        while j < CHANNELS[i].len() {
+            h = hash(h, &[CHANNELS[i][j] as u8]);
+            j += 1;
+        }
+        h = hash(h, &[0xff]);
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < SCHEMA.len() {
+        h = hash(h, SCHEMA[i].name.as_bytes());
+        h = hash(h, &(SCHEMA[i].len as u32).to_le_bytes());
+        h = hash(h, &[SCHEMA[i].encoding as u8]);
+        i += 1;
+    }
+
+    h
+}
+
+/// Observations are all either zero or one, so the robot packs them into a
+/// bitmask of this many words.
+pub const OBSERVATION_WORDS: usize = OBSERVATIONS.div_ceil(32);
+
+/// Step forward, step backward, turn left, turn right, stab, wait.
+pub const ACTIONS: usize = 6;
+
+/// Actions that swap places in mirrored situations.
+pub const TURN_LEFT: usize = 2;
+pub const TURN_RIGHT: usize = 3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kartoffel_nn::xorshift;
+
+    #[test]
+    fn decode_undoes_encode() {
+        let r = (RADAR_SIZE / 2) as i8;
+        let has_heading = SCHEMA.iter().any(|feature| feature.source == Source::Heading);
+
+        let mut seed = 0x68e3_1da4u32;
+        for _ in 0..1000 {
+            let tiles: Vec<_> = (0..RADAR_SIZE * RADAR_SIZE)
+                .map(|_| Tile::from_char(['.', '-', '|', '+', ' ', '@', '?'][xorshift(&mut seed) as usize % 7]))
+                .collect();
+            let tile = |x: i8, y: i8| tiles[(y + r) as usize * RADAR_SIZE + (x + r) as usize];
+            let arm_cooling_down = xorshift(&mut seed) % 2 == 0;
+            let heading = [None, Some(0), Some(1), Some(2), Some(3)][xorshift(&mut seed) as usize % 5];
+
+            let mut observations = [false; OBSERVATIONS];
+            encode(tile, arm_cooling_down, heading, |i| observations[i] = true);
+
+            let expected = Decoded {
+                radar: core::array::from_fn(|y| core::array::from_fn(|x| tile(x as i8 - r, y as i8 - r).channel())),
+                arm_cooling_down,
+                heading: heading.filter(|_| has_heading),
+            };
+            assert_eq!(decode(|i| observations[i]), expected);
+        }
+    }
+
+    // Models carry the hash, so a change to how it comes about would make the
+    // build refuse every model trained so far
+
+    #[test]
+    #[cfg(not(any(feature = "one-hot-tiles", feature = "radar-3x3", feature = "radar-5x5", feature = "radar-9x9", feature = "compass")))]
+    fn default_schema_hash_is_v3s() {
+        assert_eq!(SCHEMA_HASH, crate::kartoffel_nn::format::V3_SCHEMA_HASH);
+    }
+
+    #[test]
+    #[cfg(all(feature = "compass", not(any(feature = "one-hot-tiles", feature = "radar-3x3", feature = "radar-5x5", feature = "radar-9x9"))))]
+    fn compass_schema_hash_is_stable() {
+        let expected = if cfg!(feature = "allocentric") { 0xec54_b1c9 } else { 0x14a5_42a2 };
+        assert_eq!(SCHEMA_HASH, expected);
+    }
+}
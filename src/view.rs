@@ -0,0 +1,69 @@
+use kartoffel::RadarScan;
+
+/// Tile the robot hasn't seen (yet), which `Tile::from_char` makes out to be
+/// `Tile::Unknown`.
+pub const UNSEEN: char = '?';
+
+/// `N`x`N` tiles around the robot, as of the scans it took - a scan smaller
+/// than that only refreshes the middle, the rest staying what the previous
+/// scans saw; as with `RadarScan`, the robot is in the middle, facing `dy < 0`.
+///
+/// The robot keeps it up to date with its own moves, so that it stays in the
+/// robot's frame; what moves in from outside is `UNSEEN`, while enemies that
+/// moved in the meantime are where they were last seen.
+pub struct View<const N: usize> {
+    tiles: [[char; N]; N],
+}
+
+impl<const N: usize> View<N> {
+    const R: i8 = (N / 2) as i8;
+
+    pub const fn new() -> Self {
+        View { tiles: [[UNSEEN; N]; N] }
+    }
+
+    pub fn at(&self, dx: i8, dy: i8) -> char {
+        self.tiles[(dy + Self::R) as usize][(dx + Self::R) as usize]
+    }
+
+    /// Takes in what `scan` saw, leaving out whatever is beyond the view.
+    pub fn merge<const M: usize>(&mut self, scan: &RadarScan<M>) {
+        let r = (M / 2) as i8;
+        for dy in -r.min(Self::R)..=r.min(Self::R) {
+            for dx in -r.min(Self::R)..=r.min(Self::R) {
+                self.tiles[(dy + Self::R) as usize][(dx + Self::R) as usize] = scan.at(dx, dy);
+            }
+        }
+    }
+
+    /// Follows the robot stepping forward (`dy = -1`) or backward (`dy = 1`).
+    pub fn step(&mut self, dy: i8) {
+        self.remap(|x, y| (x, y + dy));
+    }
+
+    /// Follows the robot turning left, i.e. what was on its left is now in
+    /// front of it.
+    pub fn turn_left(&mut self) {
+        self.remap(|x, y| (y, -x));
+    }
+
+    pub fn turn_right(&mut self) {
+        self.remap(|x, y| (-y, x));
+    }
+
+    /// Moves into every tile the one `from` says it was at before.
+    fn remap(&mut self, from: impl Fn(i8, i8) -> (i8, i8)) {
+        let tiles = self.tiles;
+        for dy in -Self::R..=Self::R {
+            for dx in -Self::R..=Self::R {
+                let (x, y) = from(dx, dy);
+                let tile = if x.abs() <= Self::R && y.abs() <= Self::R {
+                    tiles[(y + Self::R) as usize][(x + Self::R) as usize]
+                } else {
+                    UNSEEN
+                };
+                self.tiles[(dy + Self::R) as usize][(dx + Self::R) as usize] = tile;
+            }
+        }
+    }
+}